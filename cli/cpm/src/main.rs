@@ -1,11 +1,13 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap::Parser;
 use cli::{Cli, Commands};
 use cosm_orc::config::cfg::{ChainConfig, Config, ConfigInput};
 use cosm_orc::orchestrator::cosm_orc::CosmOrc;
 use cosm_orc::orchestrator::deploy::DeployInfo;
 use cosmwasm_std::Addr;
-use cw_code_id_registry::msg::{GetRegistrationResponse, QueryMsg};
+use cw_code_id_registry::msg::{
+    GetRegistrationResponse, QueryMsg, ResolveManyResponse, ResolveRequest, MAX_RESOLVE_BATCH_SIZE,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -33,6 +35,11 @@ pub struct CPMConfig {
 pub struct Dependency {
     /// Optional version, if ommitted latest version of contract will be used
     pub version: Option<String>,
+    /// Optional tag such as `stable` or `beta` to follow instead of a version
+    pub tag: Option<String>,
+    /// Whether pre-releases such as `v1.0.0-rc.1` may be used as the latest version
+    #[serde(default)]
+    pub include_prerelease: bool,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -44,6 +51,8 @@ pub struct LockFile {
 pub struct LockDep {
     pub name: ContractName,
     pub chain_id: ChainID,
+    /// registry the registration was resolved from, which may be an upstream of `registry_addr`
+    pub registry: Addr,
     pub registered_by: Addr,
     pub version: String,
     pub code_id: u64,
@@ -63,7 +72,7 @@ fn config_dir(cfg: Option<PathBuf>) -> Result<String> {
     };
 
     let mut config_dir = config_dir.to_str().context("invalid dir name")?;
-    if config_dir == "" {
+    if config_dir.is_empty() {
         config_dir = ".";
     }
 
@@ -114,31 +123,80 @@ fn install(cfg: &CPMConfig, config_dir: &str, orc: &CosmOrc) -> Result<()> {
         dependencies: vec![],
     };
 
+    let mut resolved: Vec<(ContractName, ChainID, GetRegistrationResponse)> = vec![];
+    let mut to_resolve: Vec<ResolveRequest> = vec![];
+
     for (chain_id, deps) in &cfg.dependencies {
         for (contract_name, dep) in deps {
-            let res: GetRegistrationResponse = orc
-                .query(
-                    CPM_REGISTRY_NAME,
-                    &QueryMsg::GetRegistration {
+            match (&dep.version, &dep.tag) {
+                (Some(_), Some(_)) => bail!(
+                    "{} on {} has both a version and a tag, only one can be used",
+                    contract_name,
+                    chain_id
+                ),
+                (_, Some(tag)) => {
+                    let msg = QueryMsg::GetTaggedRegistration {
                         name: contract_name.clone(),
                         chain_id: chain_id.clone(),
-                        version: dep.version.clone(),
-                    },
-                )?
-                .data()?;
-
-            let reg = res.registration;
-            lock_file.dependencies.push(LockDep {
-                name: contract_name.clone(),
-                chain_id: chain_id.clone(),
-                registered_by: reg.registered_by,
-                version: reg.version,
-                code_id: reg.code_id,
-                checksum: reg.checksum,
-            })
+                        tag: tag.clone(),
+                    };
+                    let res: GetRegistrationResponse =
+                        orc.query(CPM_REGISTRY_NAME, &msg)?.data()?;
+                    resolved.push((contract_name.clone(), chain_id.clone(), res));
+                }
+                (version, None) => to_resolve.push(ResolveRequest {
+                    name: contract_name.clone(),
+                    chain_id: chain_id.clone(),
+                    version: version.clone(),
+                    include_prerelease: Some(dep.include_prerelease),
+                }),
+            }
+        }
+    }
+
+    // Resolve untagged dependencies in as few queries as possible.
+    for requests in to_resolve.chunks(MAX_RESOLVE_BATCH_SIZE) {
+        let msg = QueryMsg::ResolveMany {
+            requests: requests.to_vec(),
+        };
+        let res: ResolveManyResponse = orc.query(CPM_REGISTRY_NAME, &msg)?.data()?;
+        for (request, result) in requests.iter().zip(res.results) {
+            match (result.registration, result.error) {
+                (Some(res), _) => {
+                    resolved.push((request.name.clone(), request.chain_id.clone(), res))
+                }
+                (None, error) => bail!(
+                    "failed to resolve {} on {}: {}",
+                    request.name,
+                    request.chain_id,
+                    error.unwrap_or_default()
+                ),
+            }
         }
     }
 
+    for (contract_name, chain_id, res) in resolved {
+        if res.redirected_from.is_some() {
+            println!(
+                "{} on {} has been renamed to {}",
+                contract_name, chain_id, res.name
+            );
+        }
+
+        let reg = res.registration;
+        lock_file.dependencies.push(LockDep {
+            name: contract_name,
+            chain_id,
+            registry: res
+                .source
+                .unwrap_or_else(|| Addr::unchecked(&cfg.registry_addr)),
+            registered_by: reg.registered_by,
+            version: reg.version,
+            code_id: reg.code_id,
+            checksum: reg.checksum,
+        })
+    }
+
     // TODO: Sort the dependencies in alpha order to make the lockfile deterministic
 
     fs::write(
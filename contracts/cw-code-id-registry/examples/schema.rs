@@ -4,11 +4,16 @@ use std::fs::create_dir_all;
 use cosmwasm_schema::{export_schema, export_schema_with_title, remove_schemas, schema_for};
 
 use cw_code_id_registry::{
+    ibc::{RegistryAck, RegistryPacket},
     msg::{
-        ExecuteMsg, GetRegistrationResponse, InfoForCodeIdResponse, InstantiateMsg,
-        ListRegistrationsResponse, QueryMsg, ReceiveMsg,
+        CodeIdRegistryHookExecuteMsg, ExecuteMsg, ExportResponse, GetChainResponse, GetFeeResponse,
+        GetNonceResponse, GetRegistrationResponse, IdentifyContractResponse, InfoForCodeIdResponse,
+        InstantiateMsg, InstantiatePackageResponse, ListChainsResponse, ListHooksResponse,
+        ListIbcChannelsResponse, ListRegistrationsByRegistrantResponse, ListRegistrationsResponse,
+        ListTagsResponse, MigrationPathResponse, PauseStatusResponse, QueryMsg, ReceiveMsg,
+        RegisterSignedPayload, ResolveManyResponse, ReverseDependenciesResponse,
     },
-    state::{Config, PaymentInfo, Registration},
+    state::{Chain, Config, PaymentInfo, Registration, Tombstone},
 };
 
 fn main() {
@@ -26,8 +31,37 @@ fn main() {
     export_schema(&schema_for!(GetRegistrationResponse), &out_dir);
     export_schema(&schema_for!(InfoForCodeIdResponse), &out_dir);
     export_schema(&schema_for!(ListRegistrationsResponse), &out_dir);
+    export_schema(&schema_for!(ListTagsResponse), &out_dir);
+    export_schema(&schema_for!(GetFeeResponse), &out_dir);
+    export_schema(&schema_for!(PauseStatusResponse), &out_dir);
+    export_schema(
+        &schema_for!(ListRegistrationsByRegistrantResponse),
+        &out_dir,
+    );
+    export_schema(&schema_for!(ResolveManyResponse), &out_dir);
+    export_schema(&schema_for!(InstantiatePackageResponse), &out_dir);
+    export_schema(&schema_for!(RegisterSignedPayload), &out_dir);
+    export_schema(&schema_for!(GetNonceResponse), &out_dir);
+    export_schema(&schema_for!(CodeIdRegistryHookExecuteMsg), &out_dir);
+    export_schema(&schema_for!(ListHooksResponse), &out_dir);
+    export_schema(&schema_for!(ReverseDependenciesResponse), &out_dir);
+    export_schema(&schema_for!(MigrationPathResponse), &out_dir);
+    export_schema(&schema_for!(IdentifyContractResponse), &out_dir);
+    export_schema(&schema_for!(Chain), &out_dir);
+    export_schema(&schema_for!(GetChainResponse), &out_dir);
+    export_schema(&schema_for!(ListChainsResponse), &out_dir);
+    export_schema(&schema_for!(ExportResponse), &out_dir);
+    export_schema(&schema_for!(ListIbcChannelsResponse), &out_dir);
+    export_schema(&schema_for!(RegistryPacket), &out_dir);
+    export_schema(&schema_for!(RegistryAck), &out_dir);
 
     // Auto TS code generation expects the query return type as QueryNameResponse
     // Here we map query responses to the correct name
     export_schema_with_title(&schema_for!(Config), &out_dir, "ConfigResponse");
+    export_schema_with_title(
+        &schema_for!(GetRegistrationResponse),
+        &out_dir,
+        "GetTaggedRegistrationResponse",
+    );
+    export_schema_with_title(&schema_for!(Tombstone), &out_dir, "GetTombstoneResponse");
 }
@@ -1,27 +1,60 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, from_binary, to_binary, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, Order, Response, StdError, StdResult, Uint128, WasmMsg,
+    coins, from_binary, from_slice, to_binary, to_vec, Addr, BankMsg, Binary, CanonicalAddr, Coin,
+    ContractInfoResponse, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint128,
+    WasmMsg, WasmQuery,
 };
-use cw2::set_contract_version;
+use cw2::{set_contract_version, ContractVersion, CONTRACT};
 use cw20::{Cw20ReceiveMsg, TokenInfoResponse};
-use cw_utils::must_pay;
+use cw_storage_plus::{Bound, KeyDeserialize, Map, PrimaryKey};
+use cw_utils::{one_coin, parse_reply_instantiate_data, Duration, Expiration};
+use ripemd::Ripemd160;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
 
 use crate::error::ContractError;
+use crate::ibc::{broadcast_msgs, RegistryPacket};
 use crate::msg::{
-    ExecuteMsg, GetRegistrationResponse, InfoForCodeIdResponse, InstantiateMsg,
-    ListRegistrationsResponse, QueryMsg, ReceiveMsg,
+    CodeIdRegistryHookMsg, ExecuteMsg, ExportEntry, ExportKey, ExportResponse, GetChainResponse,
+    GetFeeResponse, GetNonceResponse, GetRegistrationResponse, IbcChannelEntry,
+    IdentifyContractResponse, InfoForCodeIdResponse, InstantiateMsg, InstantiatePackageMsg,
+    InstantiatePackageResponse, ListChainsResponse, ListHooksResponse, ListIbcChannelsResponse,
+    ListRegistrationsByRegistrantResponse, ListRegistrationsResponse, ListTagsResponse,
+    MigrationHop, MigrationPathResponse, PauseStatusResponse, QueryMsg, ReceiveMsg,
+    RegisterSignedPayload, RegistrantEntry, ResolveManyResponse, ResolveRequest, ResolveResult,
+    ReverseDependenciesResponse, ReverseDependency, SignedRegistration, MAX_RESOLVE_BATCH_SIZE,
 };
 use crate::state::{
-    Config, PaymentInfo, Registration, CHAIN_ID_CODE_ID_TO_NAME, CONFIG,
-    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION, NAME_CHAIN_ID_TO_OWNER,
-    NAME_CHAIN_ID_VERSION_TO_CODE_ID,
+    Chain, Config, Deposit, GracePeriod, Migration, PaymentInfo, RateLimit, RateLimitWindow,
+    Registration, Tombstone, CHAINS, CHAIN_ALIAS_TO_CHAIN_ID, CHAIN_ID_CODE_ID_TO_NAME,
+    CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME, CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION,
+    CHAIN_ID_TO_PAYMENT_INFO, CONFIG, DEFAULT_HOOK_GAS_LIMIT, DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME,
+    FEE_EXEMPT_ADDRESSES, GLOBAL_HOOKS, IBC_CHANNEL_ID_TO_CONNECTION_ID,
+    IBC_CONNECTION_ID_TO_CHAIN_ID, IMPORT_SEALED, NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT,
+    NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD, NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION,
+    NAME_CHAIN_ID_HOOK, NAME_CHAIN_ID_TAG_TO_VERSION, NAME_CHAIN_ID_TO_ALIAS_TARGET,
+    NAME_CHAIN_ID_TO_OWNER, NAME_CHAIN_ID_VERSION_TO_CODE_ID, NAME_CHAIN_ID_VERSION_TO_TOMBSTONE,
+    NAME_TO_PAYMENT_INFO, PAUSED_UNTIL, REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME,
+    REGISTRANT_TO_RATE_LIMIT_WINDOW, SIGNER_NONCE,
 };
+use crate::version::{is_prerelease, parse_version, parse_version_req, same_version};
 
 const CONTRACT_NAME: &str = "crates.io:cw-code-id-registry";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+const HOOK_REPLY_ID: u64 = 0;
+const INSTANTIATE_REPLY_ID: u64 = 1;
+
+/// Tag implicitly pointing at the most recent registration.
+const LATEST_TAG: &str = "latest";
+
 fn assert_cw20(deps: Deps, cw20_addr: &Addr) -> Result<(), ContractError> {
     let _resp: TokenInfoResponse = deps
         .querier
@@ -30,30 +63,280 @@ fn assert_cw20(deps: Deps, cw20_addr: &Addr) -> Result<(), ContractError> {
     Ok(())
 }
 
-fn validate_payment_info(deps: Deps, payment_info: &PaymentInfo) -> Result<(), ContractError> {
-    match payment_info {
-        PaymentInfo::None {} => {}
-        PaymentInfo::Cw20Payment {
-            token_address,
-            payment_amount,
-        } => {
-            if payment_amount.is_zero() {
-                return Err(ContractError::IncorrectPaymentAmount {});
-            }
-
-            // Validate it is a valid CW20 address
-            let payment_token_address = deps.api.addr_validate(token_address)?;
-            assert_cw20(deps, &payment_token_address)?;
+fn validate_payment_info(deps: Deps, payment_info: &[PaymentInfo]) -> Result<(), ContractError> {
+    for (idx, payment) in payment_info.iter().enumerate() {
+        // Each denom or CW20 may only be configured once.
+        if payment_info[..idx]
+            .iter()
+            .any(|other| payment_token(other) == payment_token(payment))
+        {
+            return Err(ContractError::DuplicatePaymentToken(
+                payment_token(payment).to_string(),
+            ));
         }
-        PaymentInfo::NativePayment { payment_amount, .. } => {
-            if payment_amount.is_zero() {
-                return Err(ContractError::IncorrectPaymentAmount {});
+
+        match payment {
+            PaymentInfo::Cw20Payment {
+                token_address,
+                payment_amount,
+            } => {
+                if payment_amount.is_zero() {
+                    return Err(ContractError::IncorrectPaymentAmount {});
+                }
+
+                // Validate it is a valid CW20 address
+                let payment_token_address = deps.api.addr_validate(token_address)?;
+                assert_cw20(deps, &payment_token_address)?;
+            }
+            PaymentInfo::NativePayment { payment_amount, .. } => {
+                if payment_amount.is_zero() {
+                    return Err(ContractError::IncorrectPaymentAmount {});
+                }
             }
         }
     }
     Ok(())
 }
 
+/// Returns the native denom or CW20 address a payment is made in.
+fn payment_token(payment: &PaymentInfo) -> &str {
+    match payment {
+        PaymentInfo::NativePayment { token_denom, .. } => token_denom,
+        PaymentInfo::Cw20Payment { token_address, .. } => token_address,
+    }
+}
+
+fn validate_fee_recipients(
+    deps: Deps,
+    fee_recipients: Vec<(String, Decimal)>,
+) -> Result<Vec<(Addr, Decimal)>, ContractError> {
+    // No recipients means the admin receives all fees.
+    if fee_recipients.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut total = Decimal::zero();
+    let validated = fee_recipients
+        .into_iter()
+        .map(|(recipient, share)| {
+            if share.is_zero() {
+                return Err(ContractError::InvalidFeeShares {});
+            }
+            total += share;
+            Ok((deps.api.addr_validate(&recipient)?, share))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    if total != Decimal::one() {
+        return Err(ContractError::InvalidFeeShares {});
+    }
+    Ok(validated)
+}
+
+fn payment_amount(payment: &PaymentInfo) -> Uint128 {
+    match payment {
+        PaymentInfo::NativePayment { payment_amount, .. } => *payment_amount,
+        PaymentInfo::Cw20Payment { payment_amount, .. } => *payment_amount,
+    }
+}
+
+/// Builds a message transferring an amount of a payment's token.
+fn transfer_msg(payment: &PaymentInfo, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    Ok(match payment {
+        PaymentInfo::NativePayment { token_denom, .. } => CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), token_denom),
+        }),
+        PaymentInfo::Cw20Payment { token_address, .. } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: token_address.clone(),
+            msg: to_binary(&cw20_base::msg::ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    })
+}
+
+/// Whether a name has an owner, registrations, or redirects elsewhere.
+fn name_in_use(deps: Deps, name: String, chain_id: String) -> StdResult<bool> {
+    Ok(
+        NAME_CHAIN_ID_TO_OWNER.has(deps.storage, (name.clone(), chain_id.clone()))
+            || NAME_CHAIN_ID_TO_ALIAS_TARGET.has(deps.storage, (name.clone(), chain_id.clone()))
+            || NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                .prefix((name, chain_id))
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_some(),
+    )
+}
+
+pub(crate) fn is_paused(deps: Deps, env: &Env) -> StdResult<bool> {
+    Ok(PAUSED_UNTIL
+        .may_load(deps.storage)?
+        .is_some_and(|until| !until.is_expired(&env.block)))
+}
+
+/// Returns the payments accepted from `sender` to register `name` on
+/// `chain_id`. Fee exempt senders pay nothing. Otherwise a name override
+/// takes precedence over a chain ID override, which takes precedence over the
+/// configured payment info.
+pub fn effective_payment_info(
+    deps: Deps,
+    config: &Config,
+    name: &str,
+    chain_id: &str,
+    sender: &Addr,
+) -> StdResult<Vec<PaymentInfo>> {
+    if FEE_EXEMPT_ADDRESSES.has(deps.storage, sender.clone()) {
+        return Ok(vec![]);
+    }
+    if let Some(payment_info) = NAME_TO_PAYMENT_INFO.may_load(deps.storage, name.to_string())? {
+        return Ok(payment_info);
+    }
+    if let Some(payment_info) =
+        CHAIN_ID_TO_PAYMENT_INFO.may_load(deps.storage, chain_id.to_string())?
+    {
+        return Ok(payment_info);
+    }
+    Ok(config.payment_info.clone())
+}
+
+/// Whether two payments are made in the same native denom or CW20.
+fn same_token(payment: &PaymentInfo, other: &PaymentInfo) -> bool {
+    match (payment, other) {
+        (
+            PaymentInfo::NativePayment { token_denom, .. },
+            PaymentInfo::NativePayment {
+                token_denom: other_denom,
+                ..
+            },
+        ) => token_denom == other_denom,
+        (
+            PaymentInfo::Cw20Payment { token_address, .. },
+            PaymentInfo::Cw20Payment {
+                token_address: other_address,
+                ..
+            },
+        ) => token_address == other_address,
+        _ => false,
+    }
+}
+
+/// Sets aside the deposit required from `registrant` if `received` is in the
+/// deposit's token and covers it, returning the deposit and what is left.
+fn take_deposit(
+    config: &Config,
+    registrant: &Addr,
+    received: &PaymentInfo,
+) -> (Option<PaymentInfo>, Uint128) {
+    let amount = payment_amount(received);
+    match &config.deposit {
+        Some(deposit)
+            if *registrant != config.admin
+                && same_token(deposit, received)
+                && amount >= payment_amount(deposit) =>
+        {
+            (Some(deposit.clone()), amount - payment_amount(deposit))
+        }
+        _ => (None, amount),
+    }
+}
+
+/// Builds the messages returning the deposit held for a registration, if
+/// any, to whoever paid it. The deposit is marked returned rather than
+/// removed so the registration can't be unyanked without one.
+fn return_deposit_msgs(
+    storage: &mut dyn Storage,
+    name: &str,
+    chain_id: &str,
+    code_id: u64,
+) -> StdResult<Vec<CosmosMsg>> {
+    let key = (name.to_string(), chain_id.to_string(), code_id);
+    match NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT.may_load(storage, key.clone())? {
+        Some(deposit) if !deposit.returned => {
+            NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT.save(
+                storage,
+                key,
+                &Deposit {
+                    returned: true,
+                    ..deposit.clone()
+                },
+            )?;
+            Ok(vec![transfer_msg(
+                &deposit.amount,
+                &deposit.depositor,
+                payment_amount(&deposit.amount),
+            )?])
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// Finds the accepted payment in the same token as the received payment,
+/// erroring if the token is not accepted or too little was received.
+fn match_payment(
+    accepted: &[PaymentInfo],
+    received: &PaymentInfo,
+) -> Result<PaymentInfo, ContractError> {
+    let payment = accepted
+        .iter()
+        .find(|payment| same_token(payment, received))
+        .ok_or(match received {
+            PaymentInfo::NativePayment { .. } => ContractError::UnrecognizedNativeToken {},
+            PaymentInfo::Cw20Payment { .. } => ContractError::UnrecognizedCw20 {},
+        })?;
+
+    if payment_amount(received) < payment_amount(payment) {
+        return Err(ContractError::IncorrectPaymentAmount {});
+    }
+    Ok(payment.clone())
+}
+
+/// Builds the messages returning any amount paid over the fee to the sender.
+fn refund_msgs(
+    payment: &PaymentInfo,
+    sender: &Addr,
+    overpaid: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    if overpaid.is_zero() {
+        return Ok(vec![]);
+    }
+    Ok(vec![transfer_msg(payment, sender, overpaid)?])
+}
+
+/// Splits an amount between the configured fee recipients. Each recipient
+/// receives their share rounded down, and the first recipient also receives
+/// whatever dust is left over so the full amount is always paid out.
+fn split_fee(config: &Config, amount: Uint128) -> Vec<(Addr, Uint128)> {
+    if config.fee_recipients.is_empty() {
+        return vec![(config.admin.clone(), amount)];
+    }
+
+    let mut splits: Vec<(Addr, Uint128)> = config
+        .fee_recipients
+        .iter()
+        .map(|(recipient, share)| (recipient.clone(), amount * *share))
+        .collect();
+    let distributed = splits
+        .iter()
+        .fold(Uint128::zero(), |total, (_, amount)| total + *amount);
+    splits[0].1 += amount - distributed;
+
+    splits
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect()
+}
+
+/// Builds the messages sending a fee to the fee recipients.
+fn fee_msgs(config: &Config, payment: &PaymentInfo) -> StdResult<Vec<CosmosMsg>> {
+    split_fee(config, payment_amount(payment))
+        .into_iter()
+        .map(|(recipient, amount)| transfer_msg(payment, &recipient, amount))
+        .collect()
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -65,9 +348,20 @@ pub fn instantiate(
 
     validate_payment_info(deps.as_ref(), &msg.payment_info)?;
     let validated_admin = deps.api.addr_validate(&msg.admin)?;
+    let fee_recipients =
+        validate_fee_recipients(deps.as_ref(), msg.fee_recipients.unwrap_or_default())?;
     let config = Config {
         admin: validated_admin,
         payment_info: msg.payment_info,
+        fee_recipients,
+        validate_chain_ids: false,
+        upstreams: vec![],
+        unregister_window: None,
+        refund_on_unregister: false,
+        immutable: false,
+        rate_limit: None,
+        deposit: None,
+        hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -76,212 +370,1207 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // While paused, only admin messages needed to recover are allowed,
+    // including unregistering bad registrations.
+    let allowed_while_paused = matches!(
+        msg,
+        ExecuteMsg::Pause { .. }
+            | ExecuteMsg::Unpause {}
+            | ExecuteMsg::UpdateConfig { .. }
+            | ExecuteMsg::UpdateFeeExemptions { .. }
+            | ExecuteMsg::SetNameFee { .. }
+            | ExecuteMsg::SetChainIdFee { .. }
+            | ExecuteMsg::RemoveHook { .. }
+            | ExecuteMsg::SetChain { .. }
+            | ExecuteMsg::RemoveChain { .. }
+            | ExecuteMsg::Import { .. }
+            | ExecuteMsg::SealImport {}
+            | ExecuteMsg::SetUpstreams { .. }
+            | ExecuteMsg::SetIbcConnection { .. }
+            | ExecuteMsg::RemoveIbcConnection { .. }
+            | ExecuteMsg::SetUnregisterWindow { .. }
+            | ExecuteMsg::SetImmutable { .. }
+            | ExecuteMsg::SetHookGasLimit { .. }
+            | ExecuteMsg::SetRegistrationLimits { .. }
+    ) || (matches!(msg, ExecuteMsg::Unregister { .. })
+        && info.sender == CONFIG.load(deps.storage)?.admin);
+    if !allowed_while_paused && is_paused(deps.as_ref(), &env)? {
+        return Err(ContractError::Paused {});
+    }
+
     match msg {
-        ExecuteMsg::Receive(wrapped) => execute_receive(deps, info, wrapped),
+        ExecuteMsg::Receive(wrapped) => execute_receive(deps, env, info, wrapped),
         ExecuteMsg::Register {
             name,
             version,
             chain_id,
             code_id,
             checksum,
-        } => execute_register(deps, info, name, version, chain_id, code_id, checksum),
+            dependencies,
+        } => {
+            let registration = Registration {
+                registered_by: info.sender.clone(),
+                prerelease: is_prerelease(&version),
+                version,
+                code_id,
+                checksum,
+                dependencies: dependencies.unwrap_or_default(),
+                yanked: false,
+            };
+            execute_register(deps, env, info, name, chain_id, registration)
+        }
+        ExecuteMsg::RegisterSigned {
+            registration,
+            pubkey,
+            signature,
+            nonce,
+        } => {
+            let (name, chain_id, registration) = verify_signed_registration(
+                deps.branch(),
+                &env,
+                registration,
+                pubkey,
+                signature,
+                nonce,
+            )?;
+            execute_register(deps, env, info, name, chain_id, registration)
+        }
+        ExecuteMsg::InstantiatePackage(msg) => execute_instantiate_package(deps, env, info, msg),
         ExecuteMsg::SetOwner {
             name,
             chain_id,
             owner,
-        } => execute_set_owner(deps, info.sender, name, chain_id, owner),
+        } => execute_set_owner(deps, env, info.sender, name, chain_id, owner),
         ExecuteMsg::Unregister {
             name,
             chain_id,
             code_id,
-        } => execute_unregister(deps, info.sender, name, chain_id, code_id),
+            reason,
+        } => execute_unregister(deps, env, info.sender, name, chain_id, code_id, reason),
         ExecuteMsg::UpdateConfig {
             admin,
             payment_info,
-        } => execute_update_config(deps, env, info, admin, payment_info),
+            fee_recipients,
+            validate_chain_ids,
+        } => execute_update_config(
+            deps,
+            env,
+            info,
+            admin,
+            payment_info,
+            fee_recipients,
+            validate_chain_ids,
+        ),
+        ExecuteMsg::UpdateFeeExemptions { to_add, to_remove } => {
+            execute_update_fee_exemptions(deps, info.sender, to_add, to_remove)
+        }
+        ExecuteMsg::SetNameFee { name, payment_info } => {
+            execute_set_name_fee(deps, info.sender, name, payment_info)
+        }
+        ExecuteMsg::SetChainIdFee {
+            chain_id,
+            payment_info,
+        } => execute_set_chain_id_fee(deps, info.sender, chain_id, payment_info),
+        ExecuteMsg::Pause { until } => execute_pause(deps, env, info.sender, until),
+        ExecuteMsg::Unpause {} => execute_unpause(deps, info.sender),
+        ExecuteMsg::SetTag {
+            name,
+            chain_id,
+            tag,
+            version,
+        } => execute_set_tag(deps, info.sender, name, chain_id, tag, version),
+        ExecuteMsg::RemoveTag {
+            name,
+            chain_id,
+            tag,
+        } => execute_remove_tag(deps, info.sender, name, chain_id, tag),
+        ExecuteMsg::SetAlias {
+            name,
+            chain_id,
+            alias,
+        } => execute_set_alias(deps, info.sender, name, chain_id, alias),
+        ExecuteMsg::RemoveAlias { alias, chain_id } => {
+            execute_remove_alias(deps, info.sender, alias, chain_id)
+        }
+        ExecuteMsg::Rename {
+            name,
+            chain_id,
+            new_name,
+        } => execute_rename(deps, info.sender, name, chain_id, new_name),
+        ExecuteMsg::DeclareMigration {
+            name,
+            chain_id,
+            from_version,
+            to_version,
+            migrate_msg_schema_hash,
+            notes,
+        } => execute_declare_migration(
+            deps,
+            info.sender,
+            name,
+            chain_id,
+            from_version,
+            to_version,
+            Migration {
+                migrate_msg_schema_hash,
+                notes,
+            },
+        ),
+        ExecuteMsg::RemoveMigration {
+            name,
+            chain_id,
+            from_version,
+            to_version,
+        } => execute_remove_migration(deps, info.sender, name, chain_id, from_version, to_version),
+        ExecuteMsg::AddHook {
+            name,
+            chain_id,
+            addr,
+        } => execute_add_hook(deps, info.sender, name, chain_id, addr),
+        ExecuteMsg::RemoveHook {
+            name,
+            chain_id,
+            addr,
+        } => execute_remove_hook(deps, info.sender, name, chain_id, addr),
+        ExecuteMsg::SetChain { chain } => execute_set_chain(deps, info.sender, chain),
+        ExecuteMsg::RemoveChain { chain_id } => execute_remove_chain(deps, info.sender, chain_id),
+        ExecuteMsg::Import { entries } => execute_import(deps, env, info.sender, entries),
+        ExecuteMsg::SealImport {} => execute_seal_import(deps, info.sender),
+        ExecuteMsg::SetUpstreams { upstreams } => {
+            execute_set_upstreams(deps, env, info.sender, upstreams)
+        }
+        ExecuteMsg::SetIbcConnection {
+            connection_id,
+            chain_id,
+        } => execute_set_ibc_connection(deps, info.sender, connection_id, chain_id),
+        ExecuteMsg::RemoveIbcConnection { connection_id } => {
+            execute_remove_ibc_connection(deps, info.sender, connection_id)
+        }
+        ExecuteMsg::SetUnregisterWindow { window, refund } => {
+            execute_set_unregister_window(deps, info.sender, window, refund)
+        }
+        ExecuteMsg::SetHookGasLimit { gas_limit } => {
+            execute_set_hook_gas_limit(deps, info.sender, gas_limit)
+        }
+        ExecuteMsg::SetImmutable { immutable } => {
+            execute_set_immutable(deps, info.sender, immutable)
+        }
+        ExecuteMsg::SetRegistrationLimits {
+            rate_limit,
+            deposit,
+        } => execute_set_registration_limits(deps, info.sender, rate_limit, deposit),
+        ExecuteMsg::ReleaseFee {
+            name,
+            chain_id,
+            code_id,
+        } => execute_release_fee(deps, env, name, chain_id, code_id),
+        ExecuteMsg::Yank {
+            name,
+            chain_id,
+            code_id,
+        } => execute_set_yanked(deps, env, info.sender, name, chain_id, code_id, true),
+        ExecuteMsg::Unyank {
+            name,
+            chain_id,
+            code_id,
+        } => execute_set_yanked(deps, env, info.sender, name, chain_id, code_id, false),
     }
 }
 
 pub fn execute_receive(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     wrapped: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    match config.payment_info {
-        PaymentInfo::Cw20Payment {
-            token_address,
-            payment_amount,
+    let sender = deps.api.addr_validate(&wrapped.sender)?;
+    let msg: ReceiveMsg = from_binary(&wrapped.msg)?;
+    let received = PaymentInfo::Cw20Payment {
+        token_address: info.sender.to_string(),
+        payment_amount: wrapped.amount,
+    };
+
+    let (name, chain_id, registration) = match msg {
+        ReceiveMsg::Register {
+            name,
+            version,
+            chain_id,
+            code_id,
+            checksum,
+            dependencies,
+        } => (
+            name,
+            chain_id,
+            Registration {
+                registered_by: sender.clone(),
+                prerelease: is_prerelease(&version),
+                version,
+                code_id,
+                checksum,
+                dependencies: dependencies.unwrap_or_default(),
+                yanked: false,
+            },
+        ),
+        ReceiveMsg::RegisterSigned {
+            registration,
+            pubkey,
+            signature,
+            nonce,
         } => {
-            if info.sender != token_address {
-                return Err(ContractError::UnrecognizedCw20 {});
-            }
+            verify_signed_registration(deps.branch(), &env, registration, pubkey, signature, nonce)?
+        }
+    };
 
-            let sender = deps.api.addr_validate(&wrapped.sender)?;
-            let amount = wrapped.amount;
-            let msg: ReceiveMsg = from_binary(&wrapped.msg)?;
+    // Fee exemptions apply to the registrant, not a relayer.
+    let payment_info = effective_payment_info(
+        deps.as_ref(),
+        &config,
+        &name,
+        &chain_id,
+        &registration.registered_by,
+    )?;
 
-            if payment_amount != amount {
-                return Err(ContractError::IncorrectPaymentAmount {});
-            }
+    // The sender reported by a CW20 is only trusted from tokens accepted for
+    // the fee or deposit, since anyone can send a Receive message.
+    if !payment_info
+        .iter()
+        .chain(config.deposit.iter())
+        .any(|payment| same_token(payment, &received))
+    {
+        return Err(ContractError::UnrecognizedCw20 {});
+    }
 
-            match msg {
-                ReceiveMsg::Register {
-                    name,
-                    version,
-                    chain_id,
-                    code_id,
-                    checksum,
-                } => register_code_id(
-                    deps,
-                    amount,
-                    name,
-                    chain_id,
-                    Registration {
-                        registered_by: sender,
-                        version,
-                        code_id,
-                        checksum,
-                    },
-                ),
-            }
-        }
-        _ => Err(ContractError::InvalidPayment {}),
+    // Set aside the deposit, leaving the rest to pay the fee with.
+    let (deposit, amount) = take_deposit(&config, &registration.registered_by, &received);
+    let received = PaymentInfo::Cw20Payment {
+        token_address: info.sender.to_string(),
+        payment_amount: amount,
+    };
+
+    // No accepted payments means registering is free, so return everything
+    // that was sent.
+    let (fee, refund) = if payment_info.is_empty() {
+        let refund = refund_msgs(&received, &sender, amount)?;
+        (None, refund)
+    } else {
+        let payment = match_payment(&payment_info, &received)?;
+        let overpaid = amount - payment_amount(&payment);
+        let refund = refund_msgs(&payment, &sender, overpaid)?;
+        (Some(payment), refund)
+    };
+
+    let payment = RegistrationPayment {
+        payer: sender,
+        fee,
+        deposit,
+    };
+    Ok(register_code_id(deps, &env, payment, name, chain_id, registration)?.add_messages(refund))
+}
+
+/// Verifies a registration signed by its registrant's secp256k1 key, which
+/// is used as the registrant instead of the sender relaying it.
+fn verify_signed_registration(
+    deps: DepsMut,
+    env: &Env,
+    registration: SignedRegistration,
+    pubkey: Binary,
+    signature: Binary,
+    nonce: u64,
+) -> Result<(String, String, Registration), ContractError> {
+    let payload = RegisterSignedPayload {
+        registry: env.contract.address.to_string(),
+        registry_chain_id: env.block.chain_id.clone(),
+        nonce,
+        registration: registration.clone(),
+    };
+    let hash = Sha256::digest(&to_vec(&payload)?);
+    if !deps
+        .api
+        .secp256k1_verify(&hash, &signature, &pubkey)
+        .map_err(|_| ContractError::InvalidSignature {})?
+    {
+        return Err(ContractError::InvalidSignature {});
     }
+
+    // Cosmos SDK addresses are the RIPEMD-160 hash of the SHA-256 hash of the
+    // compressed public key.
+    let signer = deps.api.addr_humanize(&CanonicalAddr::from(
+        Ripemd160::digest(Sha256::digest(pubkey.as_slice())).as_slice(),
+    ))?;
+
+    // Each nonce may only be used once, in order.
+    let expected = SIGNER_NONCE
+        .may_load(deps.storage, signer.clone())?
+        .unwrap_or_default();
+    if nonce != expected {
+        return Err(ContractError::InvalidNonce { expected });
+    }
+    SIGNER_NONCE.save(deps.storage, signer.clone(), &(nonce + 1))?;
+
+    Ok((
+        registration.name,
+        registration.chain_id,
+        Registration {
+            registered_by: signer,
+            prerelease: is_prerelease(&registration.version),
+            version: registration.version,
+            code_id: registration.code_id,
+            checksum: registration.checksum,
+            dependencies: registration.dependencies.unwrap_or_default(),
+            yanked: false,
+        },
+    ))
 }
 
 pub fn execute_register(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     name: String,
-    version: String,
     chain_id: String,
-    code_id: u64,
-    checksum: String,
+    registration: Registration,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    // Fee exemptions apply to the registrant, not a relayer.
+    let payment_info = effective_payment_info(
+        deps.as_ref(),
+        &config,
+        &name,
+        &chain_id,
+        &registration.registered_by,
+    )?;
 
-    match config.payment_info {
-        PaymentInfo::Cw20Payment { .. } => Err(ContractError::InvalidPayment {}),
-        PaymentInfo::None {} => register_code_id(
-            deps,
-            Uint128::zero(),
-            name,
-            chain_id,
-            Registration {
-                registered_by: info.sender,
-                version,
-                code_id,
-                checksum,
-            },
-        ),
-        PaymentInfo::NativePayment {
-            token_denom,
-            payment_amount,
-        } => {
-            let amount_paid = must_pay(&info, &token_denom)?;
-            if payment_amount != amount_paid {
-                return Err(ContractError::IncorrectPaymentAmount {});
+    // Set aside the deposit, leaving the rest to pay the fee with.
+    let mut deposit = None;
+    let mut funds = vec![];
+    for coin in info.funds {
+        let received = PaymentInfo::NativePayment {
+            token_denom: coin.denom.clone(),
+            payment_amount: coin.amount,
+        };
+        let amount = match deposit {
+            Some(_) => coin.amount,
+            None => {
+                let (taken, amount) = take_deposit(&config, &registration.registered_by, &received);
+                deposit = taken;
+                amount
             }
-
-            register_code_id(
-                deps,
-                amount_paid,
-                name,
-                chain_id,
-                Registration {
-                    registered_by: info.sender,
-                    version,
-                    code_id,
-                    checksum,
-                },
-            )
+        };
+        if !amount.is_zero() {
+            funds.push(Coin {
+                denom: coin.denom,
+                amount,
+            });
         }
     }
+    let info = MessageInfo {
+        sender: info.sender,
+        funds,
+    };
+
+    // No accepted payments means registering is free, so return everything
+    // that was sent.
+    let (fee, refund) = if payment_info.is_empty() {
+        let refund = if info.funds.is_empty() {
+            vec![]
+        } else {
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: info.funds.clone(),
+            })]
+        };
+        (None, refund)
+    } else {
+        let paid = one_coin(&info)?;
+        let received = PaymentInfo::NativePayment {
+            token_denom: paid.denom,
+            payment_amount: paid.amount,
+        };
+        let payment = match_payment(&payment_info, &received)?;
+        let overpaid = paid.amount - payment_amount(&payment);
+        let refund = refund_msgs(&payment, &info.sender, overpaid)?;
+        (Some(payment), refund)
+    };
+
+    let payment = RegistrationPayment {
+        payer: info.sender,
+        fee,
+        deposit,
+    };
+    Ok(register_code_id(deps, &env, payment, name, chain_id, registration)?.add_messages(refund))
+}
+
+pub fn execute_instantiate_package(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiatePackageMsg,
+) -> Result<Response, ContractError> {
+    // Funds are forwarded, so the caller must send exactly what is used.
+    let mut funds = msg.funds;
+    let mut sent = info.funds;
+    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+    sent.sort_by(|a, b| a.denom.cmp(&b.denom));
+    if funds != sent {
+        return Err(ContractError::FundsMismatch {});
+    }
+
+    let admin = msg
+        .admin
+        .map(|admin| deps.api.addr_validate(&admin))
+        .transpose()?;
+    let res = get_registration(
+        deps.as_ref(),
+        msg.name,
+        env.block.chain_id,
+        msg.version,
+        false,
+    )
+    .map_err(|_| ContractError::NotFound {})?;
+
+    let instantiate = WasmMsg::Instantiate {
+        admin: admin.map(|admin| admin.to_string()),
+        code_id: res.registration.code_id,
+        msg: msg.msg,
+        funds,
+        label: msg.label,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate_package")
+        .add_attribute("name", res.name)
+        .add_attribute("version", res.registration.version)
+        .add_attribute("code_id", res.registration.code_id.to_string())
+        .add_submessage(SubMsg::reply_on_success(instantiate, INSTANTIATE_REPLY_ID)))
 }
 
-pub fn execute_set_owner(
+pub fn execute_update_fee_exemptions(
     deps: DepsMut,
     sender: Addr,
-    name: String,
-    chain_id: String,
-    owner: Option<String>,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-
-    // Only allow admin or existing owner to set the owner.
-    let existing_owner =
-        NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
-    if sender != config.admin && Some(sender) != existing_owner {
+    // Only allow admin to manage fee exemptions.
+    if sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(owner) = owner.as_ref() {
-        let new_owner = deps.api.addr_validate(owner)?;
-        // Update owner.
-        NAME_CHAIN_ID_TO_OWNER.save(deps.storage, (name.clone(), chain_id.clone()), &new_owner)?;
-    } else {
-        // Clear owner.
-        NAME_CHAIN_ID_TO_OWNER.remove(deps.storage, (name.clone(), chain_id.clone()));
+    for address in to_add {
+        let address = deps.api.addr_validate(&address)?;
+        FEE_EXEMPT_ADDRESSES.save(deps.storage, address, &Empty {})?;
+    }
+    for address in to_remove {
+        let address = deps.api.addr_validate(&address)?;
+        FEE_EXEMPT_ADDRESSES.remove(deps.storage, address);
     }
 
-    Ok(Response::new()
-        .add_attribute("action", "set_owner")
-        .add_attribute("name", name)
-        .add_attribute("chain_id", chain_id)
-        .add_attribute("owner", owner.unwrap_or_default()))
+    Ok(Response::new().add_attribute("action", "update_fee_exemptions"))
 }
 
-pub fn execute_unregister(
+pub fn execute_set_name_fee(
     deps: DepsMut,
     sender: Addr,
     name: String,
-    chain_id: String,
-    code_id: u64,
+    payment_info: Option<Vec<PaymentInfo>>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    // Only allow admin to unregister.
+    // Only allow admin to override fees.
     if sender != config.admin {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Retrieve registration.
-    let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
-        .load(deps.storage, (name.clone(), chain_id.clone(), code_id))
-        .map_err(|_| ContractError::NotFound {})?;
-
-    // Remove from state.
-    CHAIN_ID_CODE_ID_TO_NAME.remove(deps.storage, (chain_id.clone(), code_id));
-    NAME_CHAIN_ID_VERSION_TO_CODE_ID.remove(
-        deps.storage,
-        (name.clone(), chain_id.clone(), registration.version),
-    );
-    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
-        .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+    if let Some(payment_info) = payment_info {
+        validate_payment_info(deps.as_ref(), &payment_info)?;
+        NAME_TO_PAYMENT_INFO.save(deps.storage, name.clone(), &payment_info)?;
+    } else {
+        NAME_TO_PAYMENT_INFO.remove(deps.storage, name.clone());
+    }
 
     Ok(Response::new()
-        .add_attribute("action", "unregister")
-        .add_attribute("chain_id", chain_id)
-        .add_attribute("name", name)
-        .add_attribute("code_id", code_id.to_string()))
+        .add_attribute("action", "set_name_fee")
+        .add_attribute("name", name))
 }
 
-pub fn register_code_id(
+pub fn execute_set_chain_id_fee(
     deps: DepsMut,
-    amount_sent: Uint128,
-    name: String,
+    sender: Addr,
     chain_id: String,
-    registration: Registration,
+    payment_info: Option<Vec<PaymentInfo>>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let existing_owner =
-        NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    // Only allow admin to override fees.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(payment_info) = payment_info {
+        validate_payment_info(deps.as_ref(), &payment_info)?;
+        CHAIN_ID_TO_PAYMENT_INFO.save(deps.storage, chain_id.clone(), &payment_info)?;
+    } else {
+        CHAIN_ID_TO_PAYMENT_INFO.remove(deps.storage, chain_id.clone());
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_chain_id_fee")
+        .add_attribute("chain_id", chain_id))
+}
+
+pub fn execute_set_owner(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    owner: Option<String>,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or existing owner to set the owner.
+    let existing_owner =
+        NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != existing_owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_owner = match owner.as_ref() {
+        Some(owner) => {
+            let new_owner = deps.api.addr_validate(owner)?;
+            // Update owner.
+            NAME_CHAIN_ID_TO_OWNER.save(
+                deps.storage,
+                (name.clone(), chain_id.clone()),
+                &new_owner,
+            )?;
+            Some(new_owner)
+        }
+        None => {
+            // Clear owner.
+            NAME_CHAIN_ID_TO_OWNER.remove(deps.storage, (name.clone(), chain_id.clone()));
+            None
+        }
+    };
+
+    let sync_msgs = broadcast_msgs(
+        deps.as_ref(),
+        &env,
+        &RegistryPacket::SetOwner {
+            name: name.clone(),
+            chain_id: chain_id.clone(),
+            owner: new_owner,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_messages(sync_msgs)
+        .add_attribute("action", "set_owner")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("owner", owner.unwrap_or_default()))
+}
+
+/// Points a tag at a registered version of a canonical name.
+fn save_tag(
+    deps: DepsMut,
+    name: &str,
+    chain_id: &str,
+    tag: &str,
+    version: &str,
+) -> Result<(), ContractError> {
+    // Latest is always the most recent registration.
+    if tag.is_empty() || tag == LATEST_TAG {
+        return Err(ContractError::InvalidTag(tag.to_string()));
+    }
+
+    // Can only tag registered versions.
+    if !NAME_CHAIN_ID_VERSION_TO_CODE_ID.has(
+        deps.storage,
+        (name.to_string(), chain_id.to_string(), version.to_string()),
+    ) {
+        return Err(ContractError::NotFound {});
+    }
+
+    NAME_CHAIN_ID_TAG_TO_VERSION.save(
+        deps.storage,
+        (name.to_string(), chain_id.to_string(), tag.to_string()),
+        &version.to_string(),
+    )?;
+    Ok(())
+}
+
+pub fn execute_set_tag(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    tag: String,
+    version: String,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to set tags.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    save_tag(deps, &name, &chain_id, &tag, &version)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_tag")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("tag", tag)
+        .add_attribute("version", version))
+}
+
+pub fn execute_remove_tag(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    tag: String,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to remove tags.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let key = (name.clone(), chain_id.clone(), tag.clone());
+    if !NAME_CHAIN_ID_TAG_TO_VERSION.has(deps.storage, key.clone()) {
+        return Err(ContractError::NotFound {});
+    }
+    NAME_CHAIN_ID_TAG_TO_VERSION.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_tag")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("tag", tag))
+}
+
+/// Redirects an unused alias to a name.
+fn save_alias(deps: DepsMut, name: &str, chain_id: &str, alias: &str) -> Result<(), ContractError> {
+    // Aliases must point at a canonical name, which also keeps them from
+    // forming cycles, and can't shadow a name that is already in use.
+    if NAME_CHAIN_ID_TO_ALIAS_TARGET.has(deps.storage, (name.to_string(), chain_id.to_string())) {
+        return Err(ContractError::NameIsAlias(name.to_string()));
+    }
+    if name_in_use(deps.as_ref(), alias.to_string(), chain_id.to_string())? {
+        return Err(ContractError::NameInUse(alias.to_string()));
+    }
+
+    NAME_CHAIN_ID_TO_ALIAS_TARGET.save(
+        deps.storage,
+        (alias.to_string(), chain_id.to_string()),
+        &name.to_string(),
+    )?;
+    Ok(())
+}
+
+pub fn execute_set_alias(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    alias: String,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to add aliases.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    save_alias(deps, &name, &chain_id, &alias)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_alias")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("alias", alias))
+}
+
+pub fn execute_remove_alias(
+    deps: DepsMut,
+    sender: Addr,
+    alias: String,
+    chain_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let name = NAME_CHAIN_ID_TO_ALIAS_TARGET
+        .load(deps.storage, (alias.clone(), chain_id.clone()))
+        .map_err(|_| ContractError::NotFound {})?;
+
+    // Only allow admin or owner of the name the alias points at to remove it.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name, chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    NAME_CHAIN_ID_TO_ALIAS_TARGET.remove(deps.storage, (alias.clone(), chain_id.clone()));
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_alias")
+        .add_attribute("alias", alias)
+        .add_attribute("chain_id", chain_id))
+}
+
+pub fn execute_rename(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    new_name: String,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to rename.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if name_in_use(deps.as_ref(), new_name.clone(), chain_id.clone())? {
+        return Err(ContractError::NameInUse(new_name));
+    }
+
+    // Move registrations and their indexes to the new name.
+    let registrations = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        .prefix((name.clone(), chain_id.clone()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Registration)>>>()?;
+    for (code_id, registration) in registrations {
+        NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+            .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+        NAME_CHAIN_ID_VERSION_TO_CODE_ID.remove(
+            deps.storage,
+            (name.clone(), chain_id.clone(), registration.version.clone()),
+        );
+
+        NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), code_id),
+            &registration,
+        )?;
+        NAME_CHAIN_ID_VERSION_TO_CODE_ID.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), registration.version),
+            &code_id,
+        )?;
+        CHAIN_ID_CODE_ID_TO_NAME.save(deps.storage, (chain_id.clone(), code_id), &new_name)?;
+        for dependency in registration.dependencies {
+            let dependency_name = resolve_name(deps.as_ref(), dependency.name, chain_id.clone())?;
+            DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME.save(
+                deps.storage,
+                (dependency_name, chain_id.clone(), code_id),
+                &new_name,
+            )?;
+        }
+        REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME.save(
+            deps.storage,
+            (registration.registered_by, chain_id.clone(), code_id),
+            &new_name,
+        )?;
+        if let Some(deposit) = NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT
+            .may_load(deps.storage, (name.clone(), chain_id.clone(), code_id))?
+        {
+            NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT
+                .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+            NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT.save(
+                deps.storage,
+                (new_name.clone(), chain_id.clone(), code_id),
+                &deposit,
+            )?;
+        }
+        if let Some(grace_period) = NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+            .may_load(deps.storage, (name.clone(), chain_id.clone(), code_id))?
+        {
+            NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+                .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+            NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD.save(
+                deps.storage,
+                (new_name.clone(), chain_id.clone(), code_id),
+                &grace_period,
+            )?;
+        }
+    }
+
+    // Move dependents.
+    let dependents = DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME
+        .prefix((name.clone(), chain_id.clone()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, String)>>>()?;
+    for (code_id, dependent) in dependents {
+        DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME
+            .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+        DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), code_id),
+            &dependent,
+        )?;
+    }
+
+    // Move tags.
+    let tags = NAME_CHAIN_ID_TAG_TO_VERSION
+        .prefix((name.clone(), chain_id.clone()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, String)>>>()?;
+    for (tag, version) in tags {
+        NAME_CHAIN_ID_TAG_TO_VERSION
+            .remove(deps.storage, (name.clone(), chain_id.clone(), tag.clone()));
+        NAME_CHAIN_ID_TAG_TO_VERSION.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), tag),
+            &version,
+        )?;
+    }
+
+    // Move hooks.
+    let hooks = NAME_CHAIN_ID_HOOK
+        .prefix((name.clone(), chain_id.clone()))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for hook in hooks {
+        NAME_CHAIN_ID_HOOK.remove(deps.storage, (name.clone(), chain_id.clone(), hook.clone()));
+        NAME_CHAIN_ID_HOOK.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), hook),
+            &Empty {},
+        )?;
+    }
+
+    // Move tombstones.
+    let tombstones = NAME_CHAIN_ID_VERSION_TO_TOMBSTONE
+        .prefix((name.clone(), chain_id.clone()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Tombstone)>>>()?;
+    for (version, tombstone) in tombstones {
+        NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.remove(
+            deps.storage,
+            (name.clone(), chain_id.clone(), version.clone()),
+        );
+        CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME.save(
+            deps.storage,
+            (chain_id.clone(), tombstone.code_id),
+            &new_name,
+        )?;
+        NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.save(
+            deps.storage,
+            (new_name.clone(), chain_id.clone(), version),
+            &tombstone,
+        )?;
+    }
+
+    // Move ownership.
+    if let Some(owner) = owner {
+        NAME_CHAIN_ID_TO_OWNER.remove(deps.storage, (name.clone(), chain_id.clone()));
+        NAME_CHAIN_ID_TO_OWNER.save(deps.storage, (new_name.clone(), chain_id.clone()), &owner)?;
+    }
+
+    // Redirect lookups of the old name to the new one.
+    NAME_CHAIN_ID_TO_ALIAS_TARGET.save(
+        deps.storage,
+        (name.clone(), chain_id.clone()),
+        &new_name,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "rename")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("new_name", new_name))
+}
+
+pub fn execute_unregister(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    code_id: u64,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Retrieve registration.
+    let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        .load(deps.storage, (name.clone(), chain_id.clone(), code_id))
+        .map_err(|_| ContractError::NotFound {})?;
+
+    // Allow admin to unregister at any time, and the owner only within the
+    // unregister window.
+    let grace_period = NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+        .may_load(deps.storage, (name.clone(), chain_id.clone(), code_id))?;
+    let withdrawn = sender != config.admin;
+    if withdrawn {
+        let owner =
+            NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+        match &grace_period {
+            Some(grace_period) if owner.as_ref() == Some(&sender) => {
+                if grace_period.expires.is_expired(&env.block) {
+                    return Err(ContractError::UnregisterWindowExpired {});
+                }
+            }
+            _ => return Err(ContractError::Unauthorized {}),
+        }
+    }
+
+    // Refund a held fee if the owner withdrew, otherwise pay it out as usual.
+    let fee_msgs = match grace_period {
+        Some(GracePeriod {
+            payer,
+            escrowed_fee: Some(payment),
+            ..
+        }) => {
+            if withdrawn {
+                vec![transfer_msg(&payment, &payer, payment_amount(&payment))?]
+            } else {
+                fee_msgs(&config, &payment)?
+            }
+        }
+        _ => vec![],
+    };
+    NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+        .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+    let deposit_msgs = return_deposit_msgs(deps.storage, &name, &chain_id, code_id)?;
+    NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT
+        .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+
+    // In immutable mode, retire the version and code ID for good.
+    if config.immutable {
+        NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.save(
+            deps.storage,
+            (name.clone(), chain_id.clone(), registration.version.clone()),
+            &Tombstone {
+                version: registration.version.clone(),
+                code_id,
+                removed_by: sender,
+                reason,
+            },
+        )?;
+        CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME.save(
+            deps.storage,
+            (chain_id.clone(), code_id),
+            &name,
+        )?;
+    }
+
+    // Remove from state.
+    CHAIN_ID_CODE_ID_TO_NAME.remove(deps.storage, (chain_id.clone(), code_id));
+    REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME.remove(
+        deps.storage,
+        (
+            registration.registered_by.clone(),
+            chain_id.clone(),
+            code_id,
+        ),
+    );
+    NAME_CHAIN_ID_VERSION_TO_CODE_ID.remove(
+        deps.storage,
+        (name.clone(), chain_id.clone(), registration.version.clone()),
+    );
+    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+
+    // Remove from the dependencies' reverse index.
+    for dependency in &registration.dependencies {
+        let dependency_name =
+            resolve_name(deps.as_ref(), dependency.name.clone(), chain_id.clone())?;
+        DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME
+            .remove(deps.storage, (dependency_name, chain_id.clone(), code_id));
+    }
+
+    // Remove migrations from or to the unregistered code ID.
+    let migrations = CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION
+        .sub_prefix(chain_id.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .filter(|key| {
+            key.as_ref()
+                .map_or(true, |(from, to)| *from == code_id || *to == code_id)
+        })
+        .collect::<StdResult<Vec<(u64, u64)>>>()?;
+    for (from, to) in migrations {
+        CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION
+            .remove(deps.storage, (chain_id.clone(), from, to));
+    }
+
+    // Remove tags pointing at the unregistered version.
+    let tags = NAME_CHAIN_ID_TAG_TO_VERSION
+        .prefix((name.clone(), chain_id.clone()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, String)>>>()?;
+    for (tag, version) in tags {
+        if version == registration.version {
+            NAME_CHAIN_ID_TAG_TO_VERSION
+                .remove(deps.storage, (name.clone(), chain_id.clone(), tag));
+        }
+    }
+
+    Ok(Response::new()
+        .add_messages(fee_msgs)
+        .add_messages(deposit_msgs)
+        .add_attribute("action", "unregister")
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("name", name)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+/// Errors if the code ID or version of `registration` is already registered.
+fn ensure_not_registered(
+    deps: Deps,
+    name: &str,
+    chain_id: &str,
+    registration: &Registration,
+) -> Result<(), ContractError> {
+    // Can't re-register a code ID on a chain.
+    if CHAIN_ID_CODE_ID_TO_NAME.has(deps.storage, (chain_id.to_string(), registration.code_id)) {
+        return Err(ContractError::CodeIDAlreadyRegistered(
+            registration.code_id,
+            chain_id.to_string(),
+        ));
+    }
+    if CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME
+        .has(deps.storage, (chain_id.to_string(), registration.code_id))
+    {
+        return Err(ContractError::CodeIDRemoved(
+            registration.code_id,
+            chain_id.to_string(),
+        ));
+    }
+
+    // Can't re-register a version.
+    if NAME_CHAIN_ID_VERSION_TO_CODE_ID.has(
+        deps.storage,
+        (
+            name.to_string(),
+            chain_id.to_string(),
+            registration.version.clone(),
+        ),
+    ) {
+        return Err(ContractError::VersionAlreadyRegistered(
+            registration.version.clone(),
+            name.to_string(),
+            chain_id.to_string(),
+        ));
+    }
+    if NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.has(
+        deps.storage,
+        (
+            name.to_string(),
+            chain_id.to_string(),
+            registration.version.clone(),
+        ),
+    ) {
+        return Err(ContractError::VersionRemoved(
+            registration.version.clone(),
+            name.to_string(),
+            chain_id.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Saves `registration` and indexes it by version, code ID and registrant.
+fn save_registration(
+    storage: &mut dyn Storage,
+    name: &str,
+    chain_id: &str,
+    registration: &Registration,
+) -> StdResult<()> {
+    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION.save(
+        storage,
+        (name.to_string(), chain_id.to_string(), registration.code_id),
+        registration,
+    )?;
+    NAME_CHAIN_ID_VERSION_TO_CODE_ID.save(
+        storage,
+        (
+            name.to_string(),
+            chain_id.to_string(),
+            registration.version.clone(),
+        ),
+        &registration.code_id,
+    )?;
+    CHAIN_ID_CODE_ID_TO_NAME.save(
+        storage,
+        (chain_id.to_string(), registration.code_id),
+        &name.to_string(),
+    )?;
+    REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME.save(
+        storage,
+        (
+            registration.registered_by.clone(),
+            chain_id.to_string(),
+            registration.code_id,
+        ),
+        &name.to_string(),
+    )
+}
+
+/// Saves a registration made elsewhere, such as in another registry. Its
+/// dependencies are indexed without being checked, since they may be saved
+/// after their dependents.
+pub(crate) fn save_external_registration(
+    deps: DepsMut,
+    name: &str,
+    chain_id: &str,
+    registration: &Registration,
+) -> Result<(), ContractError> {
+    ensure_not_registered(deps.as_ref(), name, chain_id, registration)?;
+    for dependency in &registration.dependencies {
+        let dependency_name =
+            resolve_name(deps.as_ref(), dependency.name.clone(), chain_id.to_string())?;
+        DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME.save(
+            deps.storage,
+            (dependency_name, chain_id.to_string(), registration.code_id),
+            &name.to_string(),
+        )?;
+    }
+    save_registration(deps.storage, name, chain_id, registration)?;
+    Ok(())
+}
+
+/// What was paid to register, and by whom.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationPayment {
+    pub payer: Addr,
+    /// The fee, if registering is not free.
+    pub fee: Option<PaymentInfo>,
+    /// The deposit set aside from what was sent, if one is required.
+    pub deposit: Option<PaymentInfo>,
+}
+
+pub fn register_code_id(
+    deps: DepsMut,
+    env: &Env,
+    payment: RegistrationPayment,
+    name: String,
+    chain_id: String,
+    registration: Registration,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let existing_owner =
+        NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
 
     // If not admin, ensure sender has access to register.
     if registration.registered_by != config.admin {
@@ -290,79 +1579,954 @@ pub fn register_code_id(
             if registration.registered_by != owner {
                 return Err(ContractError::Unauthorized {});
             }
-            // If no owner set, unauthorized.
-        } else {
-            return Err(ContractError::Unauthorized {});
+            // If no owner set, unauthorized.
+        } else {
+            return Err(ContractError::Unauthorized {});
+        }
+    }
+
+    // Only allow registering on catalogued chains, under their canonical ID.
+    if config.validate_chain_ids && !CHAINS.has(deps.storage, chain_id.clone()) {
+        if let Some(canonical) = CHAIN_ALIAS_TO_CHAIN_ID.may_load(deps.storage, chain_id.clone())? {
+            return Err(ContractError::NonCanonicalChainId(chain_id, canonical));
+        }
+        return Err(ContractError::UnknownChainId(chain_id));
+    }
+
+    // Can't register under a name that redirects elsewhere.
+    if NAME_CHAIN_ID_TO_ALIAS_TARGET.has(deps.storage, (name.clone(), chain_id.clone())) {
+        return Err(ContractError::NameIsAlias(name));
+    }
+
+    ensure_not_registered(deps.as_ref(), &name, &chain_id, &registration)?;
+
+    // Limit how often anyone but the admin may register, and hold their
+    // deposit until the registration is yanked or unregistered.
+    if registration.registered_by != config.admin {
+        if let Some(rate_limit) = &config.rate_limit {
+            let window = REGISTRANT_TO_RATE_LIMIT_WINDOW
+                .may_load(deps.storage, registration.registered_by.clone())?
+                .filter(|window| !window.expires.is_expired(&env.block));
+            let window = match window {
+                Some(window) if window.count >= rate_limit.max_registrations => {
+                    return Err(ContractError::RateLimited(window.expires));
+                }
+                Some(window) => RateLimitWindow {
+                    count: window.count + 1,
+                    ..window
+                },
+                None => RateLimitWindow {
+                    expires: rate_limit.window.after(&env.block),
+                    count: 1,
+                },
+            };
+            REGISTRANT_TO_RATE_LIMIT_WINDOW.save(
+                deps.storage,
+                registration.registered_by.clone(),
+                &window,
+            )?;
+        }
+        if let Some(required) = &config.deposit {
+            let deposit = payment.deposit.clone().ok_or_else(|| {
+                ContractError::DepositRequired(
+                    payment_amount(required),
+                    payment_token(required).to_string(),
+                )
+            })?;
+            NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT.save(
+                deps.storage,
+                (name.clone(), chain_id.clone(), registration.code_id),
+                &Deposit {
+                    depositor: payment.payer.clone(),
+                    amount: deposit,
+                    returned: false,
+                },
+            )?;
+        }
+    }
+
+    // Dependencies must be satisfied by a registration on the same chain.
+    for dependency in &registration.dependencies {
+        let req = parse_version_req(&dependency.version_req)
+            .ok_or_else(|| ContractError::InvalidVersionReq(dependency.version_req.clone()))?;
+        let dependency_name =
+            resolve_name(deps.as_ref(), dependency.name.clone(), chain_id.clone())?;
+        let satisfied = NAME_CHAIN_ID_VERSION_TO_CODE_ID
+            .prefix((dependency_name.clone(), chain_id.clone()))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .filter_map(|version| version.ok())
+            .any(|version| parse_version(&version).is_some_and(|version| req.matches(&version)));
+        if !satisfied {
+            return Err(ContractError::UnsatisfiedDependency(
+                dependency.name.clone(),
+                dependency.version_req.clone(),
+            ));
+        }
+        DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME.save(
+            deps.storage,
+            (dependency_name, chain_id.clone(), registration.code_id),
+            &name,
+        )?;
+    }
+
+    save_registration(deps.storage, &name, &chain_id, &registration)?;
+
+    // Open the unregister window, holding the fee until it expires if it may
+    // be refunded. Otherwise send payment to fee recipients.
+    let msgs = match config.unregister_window {
+        Some(window) => {
+            let escrowed_fee = payment.fee.clone().filter(|_| config.refund_on_unregister);
+            NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD.save(
+                deps.storage,
+                (name.clone(), chain_id.clone(), registration.code_id),
+                &GracePeriod {
+                    expires: window.after(&env.block),
+                    payer: payment.payer,
+                    escrowed_fee: escrowed_fee.clone(),
+                },
+            )?;
+            match (payment.fee, escrowed_fee) {
+                (Some(payment), None) => fee_msgs(&config, &payment)?,
+                _ => vec![],
+            }
+        }
+        None => match payment.fee {
+            None => vec![],
+            Some(payment) => fee_msgs(&config, &payment)?,
+        },
+    };
+
+    // Notify global and package hooks, once each. Failures are caught in
+    // reply so a broken hook cannot block registering.
+    let mut hooks = GLOBAL_HOOKS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for hook in NAME_CHAIN_ID_HOOK
+        .prefix((name.clone(), chain_id.clone()))
+        .keys(deps.storage, None, None, Order::Ascending)
+    {
+        let hook = hook?;
+        if !hooks.contains(&hook) {
+            hooks.push(hook);
+        }
+    }
+    let sync_msgs = broadcast_msgs(
+        deps.as_ref(),
+        env,
+        &RegistryPacket::Register {
+            name: name.clone(),
+            chain_id: chain_id.clone(),
+            registration: registration.clone(),
+        },
+    )?;
+    let hook_msg = CodeIdRegistryHookMsg::NewRegistration {
+        name,
+        chain_id,
+        registration,
+    };
+    let hook_msgs = hooks
+        .into_iter()
+        .map(|hook| {
+            // Without a gas limit, running out of gas would abort the
+            // registration instead of being caught in reply.
+            Ok(
+                SubMsg::reply_on_error(hook_msg.clone().into_cosmos_msg(hook)?, HOOK_REPLY_ID)
+                    .with_gas_limit(config.hook_gas_limit),
+            )
+        })
+        .collect::<StdResult<Vec<SubMsg>>>()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register")
+        .add_messages(msgs)
+        .add_messages(sync_msgs)
+        .add_submessages(hook_msgs))
+}
+
+/// The package hooks are managed for, with any alias resolved, or None for
+/// global hooks. A name without a chain ID, or the reverse, is an error
+/// rather than a request for global hooks.
+fn resolve_package(
+    deps: Deps,
+    name: Option<String>,
+    chain_id: Option<String>,
+) -> Result<Option<(String, String)>, ContractError> {
+    match (name, chain_id) {
+        (Some(name), Some(chain_id)) => Ok(Some((
+            resolve_name(deps, name, chain_id.clone())?,
+            chain_id,
+        ))),
+        (None, None) => Ok(None),
+        _ => Err(ContractError::IncompletePackage {}),
+    }
+}
+
+/// Validates that the sender may manage hooks for a package, or global
+/// hooks if no package is given.
+fn assert_can_manage_hooks(
+    deps: Deps,
+    sender: &Addr,
+    package: Option<(String, String)>,
+) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if *sender == config.admin {
+        return Ok(());
+    }
+    // Only admin can manage global hooks, and owners their package's hooks.
+    let owner = match package {
+        Some(package) => NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, package)?,
+        None => None,
+    };
+    if Some(sender) != owner.as_ref() {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    sender: Addr,
+    name: Option<String>,
+    chain_id: Option<String>,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let package = resolve_package(deps.as_ref(), name, chain_id)?;
+    assert_can_manage_hooks(deps.as_ref(), &sender, package.clone())?;
+
+    match package {
+        Some((name, chain_id)) => {
+            NAME_CHAIN_ID_HOOK.save(deps.storage, (name, chain_id, addr.clone()), &Empty {})?
+        }
+        None => GLOBAL_HOOKS.save(deps.storage, addr.clone(), &Empty {})?,
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "add_hook")
+        .add_attribute("hook", addr))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    sender: Addr,
+    name: Option<String>,
+    chain_id: Option<String>,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let package = resolve_package(deps.as_ref(), name, chain_id)?;
+    assert_can_manage_hooks(deps.as_ref(), &sender, package.clone())?;
+
+    match package {
+        Some((name, chain_id)) => {
+            let key = (name, chain_id, addr.clone());
+            if !NAME_CHAIN_ID_HOOK.has(deps.storage, key.clone()) {
+                return Err(ContractError::NotFound {});
+            }
+            NAME_CHAIN_ID_HOOK.remove(deps.storage, key);
+        }
+        None => {
+            if !GLOBAL_HOOKS.has(deps.storage, addr.clone()) {
+                return Err(ContractError::NotFound {});
+            }
+            GLOBAL_HOOKS.remove(deps.storage, addr.clone());
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_hook")
+        .add_attribute("hook", addr))
+}
+
+pub fn execute_set_hook_gas_limit(
+    deps: DepsMut,
+    sender: Addr,
+    gas_limit: u64,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Only allow admin to set the hook gas limit.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.hook_gas_limit = gas_limit;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_hook_gas_limit")
+        .add_attribute("gas_limit", gas_limit.to_string()))
+}
+
+pub fn execute_pause(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    until: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // Only allow admin to pause.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Pause indefinitely if no expiration provided.
+    let until = until.unwrap_or(Expiration::Never {});
+    if until.is_expired(&env.block) {
+        return Err(ContractError::InvalidPauseExpiration {});
+    }
+    PAUSED_UNTIL.save(deps.storage, &until)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("until", until.to_string()))
+}
+
+pub fn execute_unpause(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // Only allow admin to unpause.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    PAUSED_UNTIL.remove(deps.storage);
+
+    Ok(Response::new().add_attribute("action", "unpause"))
+}
+
+/// Loads the code IDs of two versions that a migration is declared between.
+fn migration_code_ids(
+    deps: Deps,
+    name: &str,
+    chain_id: &str,
+    from_version: String,
+    to_version: String,
+) -> Result<(u64, u64), ContractError> {
+    let from_code_id = NAME_CHAIN_ID_VERSION_TO_CODE_ID
+        .load(
+            deps.storage,
+            (name.to_string(), chain_id.to_string(), from_version),
+        )
+        .map_err(|_| ContractError::NotFound {})?;
+    let to_code_id = NAME_CHAIN_ID_VERSION_TO_CODE_ID
+        .load(
+            deps.storage,
+            (name.to_string(), chain_id.to_string(), to_version),
+        )
+        .map_err(|_| ContractError::NotFound {})?;
+    Ok((from_code_id, to_code_id))
+}
+
+pub fn execute_declare_migration(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    from_version: String,
+    to_version: String,
+    migration: Migration,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to declare migrations.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (from_code_id, to_code_id) = migration_code_ids(
+        deps.as_ref(),
+        &name,
+        &chain_id,
+        from_version.clone(),
+        to_version.clone(),
+    )?;
+    if from_code_id == to_code_id {
+        return Err(ContractError::InvalidMigration {});
+    }
+
+    CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION.save(
+        deps.storage,
+        (chain_id.clone(), from_code_id, to_code_id),
+        &migration,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "declare_migration")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("from_version", from_version)
+        .add_attribute("to_version", to_version))
+}
+
+pub fn execute_remove_migration(
+    deps: DepsMut,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    from_version: String,
+    to_version: String,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to remove migrations.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (from_code_id, to_code_id) = migration_code_ids(
+        deps.as_ref(),
+        &name,
+        &chain_id,
+        from_version.clone(),
+        to_version.clone(),
+    )?;
+    if !CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION
+        .has(deps.storage, (chain_id.clone(), from_code_id, to_code_id))
+    {
+        return Err(ContractError::NotFound {});
+    }
+    CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION
+        .remove(deps.storage, (chain_id.clone(), from_code_id, to_code_id));
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_migration")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("from_version", from_version)
+        .add_attribute("to_version", to_version))
+}
+
+/// Adds or updates a chain in the catalogue.
+fn save_chain(deps: DepsMut, chain: &Chain) -> Result<(), ContractError> {
+    if chain.chain_id.is_empty() {
+        return Err(ContractError::InvalidChain {});
+    }
+    if CHAIN_ALIAS_TO_CHAIN_ID.has(deps.storage, chain.chain_id.clone()) {
+        return Err(ContractError::ChainIdInUse(chain.chain_id.clone()));
+    }
+    for alias in &chain.aliases {
+        if alias.is_empty() || *alias == chain.chain_id {
+            return Err(ContractError::InvalidChain {});
+        }
+        let existing = CHAIN_ALIAS_TO_CHAIN_ID.may_load(deps.storage, alias.clone())?;
+        if CHAINS.has(deps.storage, alias.clone())
+            || existing.is_some_and(|chain_id| chain_id != chain.chain_id)
+        {
+            return Err(ContractError::ChainIdInUse(alias.clone()));
+        }
+    }
+
+    // Replace aliases of the existing chain, if any.
+    if let Some(existing) = CHAINS.may_load(deps.storage, chain.chain_id.clone())? {
+        for alias in existing.aliases {
+            CHAIN_ALIAS_TO_CHAIN_ID.remove(deps.storage, alias);
+        }
+    }
+    for alias in &chain.aliases {
+        CHAIN_ALIAS_TO_CHAIN_ID.save(deps.storage, alias.clone(), &chain.chain_id)?;
+    }
+    CHAINS.save(deps.storage, chain.chain_id.clone(), chain)?;
+    Ok(())
+}
+
+pub fn execute_set_chain(
+    deps: DepsMut,
+    sender: Addr,
+    chain: Chain,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // Only allow admin to manage chains.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    save_chain(deps, &chain)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_chain")
+        .add_attribute("chain_id", chain.chain_id))
+}
+
+pub fn execute_remove_chain(
+    deps: DepsMut,
+    sender: Addr,
+    chain_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // Only allow admin to manage chains.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let chain = CHAINS
+        .load(deps.storage, chain_id.clone())
+        .map_err(|_| ContractError::NotFound {})?;
+    for alias in chain.aliases {
+        CHAIN_ALIAS_TO_CHAIN_ID.remove(deps.storage, alias);
+    }
+    CHAINS.remove(deps.storage, chain_id.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_chain")
+        .add_attribute("chain_id", chain_id))
+}
+
+/// Validates config exported from another registry like the admin messages
+/// that set it.
+fn validate_config(deps: Deps, env: &Env, config: Config) -> Result<Config, ContractError> {
+    validate_payment_info(deps, &config.payment_info)?;
+    validate_registration_limits(deps, &config.rate_limit, &config.deposit)?;
+    let admin = deps.api.addr_validate(config.admin.as_str())?;
+    let fee_recipients = validate_fee_recipients(
+        deps,
+        config
+            .fee_recipients
+            .iter()
+            .map(|(recipient, share)| (recipient.to_string(), *share))
+            .collect(),
+    )?;
+    let upstreams = validate_upstreams(
+        deps,
+        env,
+        config.upstreams.iter().map(Addr::to_string).collect(),
+    )?;
+    Ok(Config {
+        admin,
+        fee_recipients,
+        upstreams,
+        ..config
+    })
+}
+
+pub fn execute_import(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    entries: Vec<ExportEntry>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    if IMPORT_SEALED.may_load(deps.storage)?.unwrap_or(false) {
+        return Err(ContractError::ImportSealed {});
+    }
+
+    let count = entries.len();
+    for entry in entries {
+        match entry {
+            ExportEntry::Chain { chain } => save_chain(deps.branch(), &chain)?,
+            ExportEntry::Alias {
+                alias,
+                chain_id,
+                name,
+            } => save_alias(deps.branch(), &name, &chain_id, &alias)?,
+            ExportEntry::Registration {
+                name,
+                chain_id,
+                mut registration,
+            } => {
+                if NAME_CHAIN_ID_TO_ALIAS_TARGET.has(deps.storage, (name.clone(), chain_id.clone()))
+                {
+                    return Err(ContractError::NameIsAlias(name));
+                }
+                registration.registered_by = deps
+                    .api
+                    .addr_validate(registration.registered_by.as_str())?;
+                save_external_registration(deps.branch(), &name, &chain_id, &registration)?;
+            }
+            ExportEntry::Tombstone {
+                name,
+                chain_id,
+                mut tombstone,
+            } => {
+                tombstone.removed_by = deps.api.addr_validate(tombstone.removed_by.as_str())?;
+                CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME.save(
+                    deps.storage,
+                    (chain_id.clone(), tombstone.code_id),
+                    &name,
+                )?;
+                NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.save(
+                    deps.storage,
+                    (name, chain_id, tombstone.version.clone()),
+                    &tombstone,
+                )?;
+            }
+            ExportEntry::Owner {
+                name,
+                chain_id,
+                owner,
+            } => {
+                let owner = deps.api.addr_validate(owner.as_str())?;
+                NAME_CHAIN_ID_TO_OWNER.save(deps.storage, (name, chain_id), &owner)?;
+            }
+            ExportEntry::Tag {
+                name,
+                chain_id,
+                tag,
+                version,
+            } => save_tag(deps.branch(), &name, &chain_id, &tag, &version)?,
+            ExportEntry::Migration {
+                chain_id,
+                from_code_id,
+                to_code_id,
+                migration,
+            } => {
+                CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION.save(
+                    deps.storage,
+                    (chain_id, from_code_id, to_code_id),
+                    &migration,
+                )?;
+            }
+            ExportEntry::Deposit {
+                name,
+                chain_id,
+                code_id,
+                mut deposit,
+            } => {
+                if !NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                    .has(deps.storage, (name.clone(), chain_id.clone(), code_id))
+                {
+                    return Err(ContractError::NotFound {});
+                }
+                deposit.depositor = deps.api.addr_validate(deposit.depositor.as_str())?;
+                NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT.save(
+                    deps.storage,
+                    (name, chain_id, code_id),
+                    &deposit,
+                )?;
+            }
+            ExportEntry::GracePeriod {
+                name,
+                chain_id,
+                code_id,
+                mut grace_period,
+            } => {
+                if !NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                    .has(deps.storage, (name.clone(), chain_id.clone(), code_id))
+                {
+                    return Err(ContractError::NotFound {});
+                }
+                grace_period.payer = deps.api.addr_validate(grace_period.payer.as_str())?;
+                NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD.save(
+                    deps.storage,
+                    (name, chain_id, code_id),
+                    &grace_period,
+                )?;
+            }
+            ExportEntry::FeeExemption { address } => {
+                let address = deps.api.addr_validate(address.as_str())?;
+                FEE_EXEMPT_ADDRESSES.save(deps.storage, address, &Empty {})?;
+            }
+            ExportEntry::NameFee { name, payment_info } => {
+                validate_payment_info(deps.as_ref(), &payment_info)?;
+                NAME_TO_PAYMENT_INFO.save(deps.storage, name, &payment_info)?;
+            }
+            ExportEntry::ChainIdFee {
+                chain_id,
+                payment_info,
+            } => {
+                validate_payment_info(deps.as_ref(), &payment_info)?;
+                CHAIN_ID_TO_PAYMENT_INFO.save(deps.storage, chain_id, &payment_info)?;
+            }
+            ExportEntry::GlobalHook { hook } => {
+                let hook = deps.api.addr_validate(hook.as_str())?;
+                GLOBAL_HOOKS.save(deps.storage, hook, &Empty {})?;
+            }
+            ExportEntry::Hook {
+                name,
+                chain_id,
+                hook,
+            } => {
+                let hook = deps.api.addr_validate(hook.as_str())?;
+                NAME_CHAIN_ID_HOOK.save(deps.storage, (name, chain_id, hook), &Empty {})?;
+            }
+            ExportEntry::IbcConnection {
+                connection_id,
+                chain_id,
+            } => {
+                IBC_CONNECTION_ID_TO_CHAIN_ID.save(deps.storage, connection_id, &chain_id)?;
+            }
+            ExportEntry::Config { config } => {
+                let config = validate_config(deps.as_ref(), &env, config)?;
+                CONFIG.save(deps.storage, &config)?;
+            }
         }
     }
 
-    // Can't re-register a code ID on a chain.
-    if CHAIN_ID_CODE_ID_TO_NAME
-        .may_load(deps.storage, (chain_id.clone(), registration.code_id))?
-        .is_some()
-    {
-        return Err(ContractError::CodeIDAlreadyRegistered(
-            registration.code_id,
-            chain_id,
-        ));
+    Ok(Response::new()
+        .add_attribute("action", "import")
+        .add_attribute("entries", count.to_string()))
+}
+
+pub fn execute_seal_import(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
     }
 
-    // Can't re-register a version.
-    if NAME_CHAIN_ID_VERSION_TO_CODE_ID
-        .may_load(
-            deps.storage,
-            (name.clone(), chain_id.clone(), registration.version.clone()),
-        )?
-        .is_some()
+    IMPORT_SEALED.save(deps.storage, &true)?;
+
+    Ok(Response::new().add_attribute("action", "seal_import"))
+}
+
+fn validate_upstreams(
+    deps: Deps,
+    env: &Env,
+    upstreams: Vec<String>,
+) -> Result<Vec<Addr>, ContractError> {
+    // An upstream that is this registry or listed twice would be queried
+    // for nothing.
+    let mut validated: Vec<Addr> = vec![];
+    for upstream in upstreams {
+        let addr = deps.api.addr_validate(&upstream)?;
+        if addr == env.contract.address || validated.contains(&addr) {
+            return Err(ContractError::InvalidUpstream(upstream));
+        }
+        validated.push(addr);
+    }
+    Ok(validated)
+}
+
+pub fn execute_set_upstreams(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    upstreams: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.upstreams = validate_upstreams(deps.as_ref(), &env, upstreams)?;
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "set_upstreams"))
+}
+
+pub fn execute_set_ibc_connection(
+    deps: DepsMut,
+    sender: Addr,
+    connection_id: String,
+    chain_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    IBC_CONNECTION_ID_TO_CHAIN_ID.save(deps.storage, connection_id.clone(), &chain_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_ibc_connection")
+        .add_attribute("connection_id", connection_id)
+        .add_attribute("chain_id", chain_id))
+}
+
+pub fn execute_remove_ibc_connection(
+    deps: DepsMut,
+    sender: Addr,
+    connection_id: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    IBC_CONNECTION_ID_TO_CHAIN_ID.remove(deps.storage, connection_id.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_ibc_connection")
+        .add_attribute("connection_id", connection_id))
+}
+
+pub fn execute_set_unregister_window(
+    deps: DepsMut,
+    sender: Addr,
+    window: Option<Duration>,
+    refund: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Only allow admin to set the unregister window.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Registrations already made keep the window they were made with.
+    config.unregister_window = window;
+    config.refund_on_unregister = refund;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_unregister_window")
+        .add_attribute("refund", refund.to_string()))
+}
+
+pub fn execute_set_immutable(
+    deps: DepsMut,
+    sender: Addr,
+    immutable: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Only allow admin to set immutable mode.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Tombstones already left stay in place either way.
+    config.immutable = immutable;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_immutable")
+        .add_attribute("immutable", immutable.to_string()))
+}
+
+fn validate_registration_limits(
+    deps: Deps,
+    rate_limit: &Option<RateLimit>,
+    deposit: &Option<PaymentInfo>,
+) -> Result<(), ContractError> {
+    if rate_limit
+        .as_ref()
+        .is_some_and(|rate_limit| rate_limit.max_registrations == 0)
     {
-        return Err(ContractError::VersionAlreadyRegistered(
-            registration.version,
-            name,
-            chain_id,
-        ));
+        return Err(ContractError::InvalidRateLimit {});
+    }
+    if let Some(deposit) = deposit {
+        validate_payment_info(deps, std::slice::from_ref(deposit))?;
+    }
+    Ok(())
+}
+
+pub fn execute_set_registration_limits(
+    deps: DepsMut,
+    sender: Addr,
+    rate_limit: Option<RateLimit>,
+    deposit: Option<PaymentInfo>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    // Only allow admin to set registration limits.
+    if sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_registration_limits(deps.as_ref(), &rate_limit, &deposit)?;
+
+    // Deposits already held are returned as before.
+    config.rate_limit = rate_limit;
+    config.deposit = deposit;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "set_registration_limits"))
+}
+
+pub fn execute_release_fee(
+    deps: DepsMut,
+    env: Env,
+    name: String,
+    chain_id: String,
+    code_id: u64,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let grace_period = NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+        .load(deps.storage, (name.clone(), chain_id.clone(), code_id))
+        .map_err(|_| ContractError::NotFound {})?;
+    if !grace_period.expires.is_expired(&env.block) {
+        return Err(ContractError::UnregisterWindowOpen {});
+    }
+    NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD
+        .remove(deps.storage, (name.clone(), chain_id.clone(), code_id));
+
+    let msgs = match grace_period.escrowed_fee {
+        Some(payment) => fee_msgs(&config, &payment)?,
+        None => vec![],
     };
 
-    // Add to state.
-    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION.save(
-        deps.storage,
-        (name.clone(), chain_id.clone(), registration.code_id),
-        &registration,
-    )?;
-    NAME_CHAIN_ID_VERSION_TO_CODE_ID.save(
-        deps.storage,
-        (name.clone(), chain_id.clone(), registration.version.clone()),
-        &registration.code_id,
-    )?;
-    CHAIN_ID_CODE_ID_TO_NAME.save(deps.storage, (chain_id, registration.code_id), &name)?;
-
-    // Send payment to admin.
-    let msgs = if amount_sent > Uint128::zero() {
-        match config.payment_info {
-            PaymentInfo::None {} => vec![],
-            PaymentInfo::NativePayment { token_denom, .. } => {
-                vec![CosmosMsg::Bank(BankMsg::Send {
-                    to_address: config.admin.to_string(),
-                    amount: coins(amount_sent.u128(), token_denom),
-                })]
-            }
-            PaymentInfo::Cw20Payment { token_address, .. } => {
-                vec![CosmosMsg::Wasm(WasmMsg::Execute {
-                    contract_addr: token_address,
-                    msg: to_binary(&cw20_base::msg::ExecuteMsg::Transfer {
-                        recipient: config.admin.to_string(),
-                        amount: amount_sent,
-                    })?,
-                    funds: vec![],
-                })]
-            }
-        }
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "release_fee")
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+pub fn execute_set_yanked(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    name: String,
+    chain_id: String,
+    code_id: u64,
+    yanked: bool,
+) -> Result<Response, ContractError> {
+    let name = resolve_name(deps.as_ref(), name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only allow admin or owner to yank.
+    let owner = NAME_CHAIN_ID_TO_OWNER.may_load(deps.storage, (name.clone(), chain_id.clone()))?;
+    if sender != config.admin && Some(sender) != owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // A yanked version's deposit has been returned, so it can't go back live.
+    if !yanked
+        && NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT
+            .may_load(deps.storage, (name.clone(), chain_id.clone(), code_id))?
+            .is_some_and(|deposit| deposit.returned)
+    {
+        return Err(ContractError::DepositReturned {});
+    }
+
+    set_yanked(deps.storage, &name, &chain_id, code_id, yanked)?;
+    let deposit_msgs = if yanked {
+        return_deposit_msgs(deps.storage, &name, &chain_id, code_id)?
     } else {
         vec![]
     };
 
+    let sync_msgs = broadcast_msgs(
+        deps.as_ref(),
+        &env,
+        &RegistryPacket::Yank {
+            name: name.clone(),
+            chain_id: chain_id.clone(),
+            code_id,
+            yanked,
+        },
+    )?;
+
     Ok(Response::new()
-        .add_attribute("action", "register")
-        .add_messages(msgs))
+        .add_messages(deposit_msgs)
+        .add_messages(sync_msgs)
+        .add_attribute("action", if yanked { "yank" } else { "unyank" })
+        .add_attribute("name", name)
+        .add_attribute("chain_id", chain_id)
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+/// Marks an existing registration as yanked or not.
+pub(crate) fn set_yanked(
+    storage: &mut dyn Storage,
+    name: &str,
+    chain_id: &str,
+    code_id: u64,
+    yanked: bool,
+) -> Result<(), ContractError> {
+    let key = (name.to_string(), chain_id.to_string(), code_id);
+    let mut registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        .load(storage, key.clone())
+        .map_err(|_| ContractError::NotFound {})?;
+    registration.yanked = yanked;
+    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION.save(storage, key, &registration)?;
+    Ok(())
 }
 
 pub fn execute_update_config(
@@ -370,7 +2534,9 @@ pub fn execute_update_config(
     _env: Env,
     info: MessageInfo,
     new_admin: Option<String>,
-    new_payment_info: Option<PaymentInfo>,
+    new_payment_info: Option<Vec<PaymentInfo>>,
+    new_fee_recipients: Option<Vec<(String, Decimal)>>,
+    new_validate_chain_ids: Option<bool>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -387,8 +2553,16 @@ pub fn execute_update_config(
     // Validate payment info
     validate_payment_info(deps.as_ref(), &new_payment_info)?;
 
+    // Validate fee recipients
+    if let Some(new_fee_recipients) = new_fee_recipients {
+        config.fee_recipients = validate_fee_recipients(deps.as_ref(), new_fee_recipients)?;
+    }
+
     config.admin = admin;
     config.payment_info = new_payment_info;
+    if let Some(new_validate_chain_ids) = new_validate_chain_ids {
+        config.validate_chain_ids = new_validate_chain_ids;
+    }
 
     CONFIG.save(deps.storage, &config)?;
 
@@ -396,66 +2570,328 @@ pub fn execute_update_config(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        // Swallow hook failures, reverting only the hook's state changes.
+        HOOK_REPLY_ID => match msg.result {
+            SubMsgResult::Err(error) => Ok(Response::new()
+                .add_attribute("action", "hook_failed")
+                .add_attribute("error", error)),
+            SubMsgResult::Ok(_) => Ok(Response::new()),
+        },
+        // Return the instantiated package's address.
+        INSTANTIATE_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)?;
+            Ok(
+                Response::new().set_data(to_binary(&InstantiatePackageResponse {
+                    contract_address: res.contract_address,
+                })?),
+            )
+        }
+        id => Err(ContractError::UnknownReplyId(id)),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
         QueryMsg::GetRegistration {
             name,
             chain_id,
             version,
-        } => query_get_registration(deps, name, chain_id, version),
+            include_prerelease,
+        } => query_get_registration(
+            deps,
+            name,
+            chain_id,
+            version,
+            include_prerelease.unwrap_or(false),
+        ),
+        QueryMsg::ResolveMany { requests } => query_resolve_many(deps, requests),
         QueryMsg::InfoForCodeId { chain_id, code_id } => {
             query_info_for_code_id(deps, chain_id, code_id)
         }
-        QueryMsg::ListRegistrations { name, chain_id } => {
-            query_list_registrations(deps, name, chain_id)
+        QueryMsg::ListRegistrations {
+            name,
+            chain_id,
+            include_prerelease,
+        } => query_list_registrations(deps, name, chain_id, include_prerelease.unwrap_or(false)),
+        QueryMsg::GetFee {
+            name,
+            chain_id,
+            sender,
+        } => query_get_fee(deps, name, chain_id, sender),
+        QueryMsg::PauseStatus {} => query_pause_status(deps, env),
+        QueryMsg::GetTaggedRegistration {
+            name,
+            chain_id,
+            tag,
+        } => query_get_tagged_registration(deps, name, chain_id, tag),
+        QueryMsg::ListTags { name, chain_id } => query_list_tags(deps, name, chain_id),
+        QueryMsg::ListRegistrationsByRegistrant {
+            address,
+            start_after,
+            limit,
+        } => query_list_registrations_by_registrant(deps, address, start_after, limit),
+        QueryMsg::GetNonce { address } => query_get_nonce(deps, address),
+        QueryMsg::ListHooks { name, chain_id } => query_list_hooks(deps, name, chain_id),
+        QueryMsg::ReverseDependencies {
+            name,
+            chain_id,
+            version,
+            start_after,
+            limit,
+        } => query_reverse_dependencies(deps, name, chain_id, version, start_after, limit),
+        QueryMsg::MigrationPath {
+            chain_id,
+            from_code_id,
+            to_version,
+        } => query_migration_path(deps, chain_id, from_code_id, to_version),
+        QueryMsg::IdentifyContract { address } => query_identify_contract(deps, env, address),
+        QueryMsg::GetChain { chain_id } => query_get_chain(deps, chain_id),
+        QueryMsg::GetTombstone {
+            name,
+            chain_id,
+            version,
+        } => query_get_tombstone(deps, name, chain_id, version),
+        QueryMsg::ListChains { start_after, limit } => query_list_chains(deps, start_after, limit),
+        QueryMsg::Export { start_after, limit } => query_export(deps, start_after, limit),
+        QueryMsg::ListIbcChannels {} => query_list_ibc_channels(deps),
+    }
+}
+
+pub fn query_get_registration(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    version: Option<String>,
+    include_prerelease: bool,
+) -> StdResult<Binary> {
+    to_binary(&get_registration(
+        deps,
+        name,
+        chain_id,
+        version,
+        include_prerelease,
+    )?)
+}
+
+fn get_registration(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    version: Option<String>,
+    include_prerelease: bool,
+) -> StdResult<GetRegistrationResponse> {
+    let canonical_name = resolve_name(deps, name.clone(), chain_id.clone())?;
+
+    // Names not registered here are looked up in each upstream in turn.
+    let registered_here = NAME_CHAIN_ID_VERSION_TO_CODE_ID
+        .prefix((canonical_name.clone(), chain_id.clone()))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+        || NAME_CHAIN_ID_VERSION_TO_TOMBSTONE
+            .prefix((canonical_name.clone(), chain_id.clone()))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_some();
+    if !registered_here {
+        let msg = QueryMsg::GetRegistration {
+            name: name.clone(),
+            chain_id: chain_id.clone(),
+            version: version.clone(),
+            include_prerelease: Some(include_prerelease),
+        };
+        for upstream in CONFIG.load(deps.storage)?.upstreams {
+            if let Ok(mut res) = deps
+                .querier
+                .query_wasm_smart::<GetRegistrationResponse>(&upstream, &msg)
+            {
+                res.source = res.source.or(Some(upstream));
+                return Ok(res);
+            }
         }
     }
+
+    let registration = load_registration(
+        deps,
+        canonical_name.clone(),
+        chain_id,
+        version,
+        include_prerelease,
+    )?;
+    Ok(GetRegistrationResponse {
+        redirected_from: (canonical_name != name).then_some(name),
+        name: canonical_name,
+        registration,
+        source: None,
+    })
+}
+
+pub fn query_resolve_many(deps: Deps, requests: Vec<ResolveRequest>) -> StdResult<Binary> {
+    if requests.len() > MAX_RESOLVE_BATCH_SIZE {
+        return Err(StdError::GenericErr {
+            msg: ContractError::BatchTooLarge(MAX_RESOLVE_BATCH_SIZE).to_string(),
+        });
+    }
+
+    // Failures are reported per request instead of failing the whole batch.
+    let results = requests
+        .into_iter()
+        .map(|request| {
+            match get_registration(
+                deps,
+                request.name,
+                request.chain_id,
+                request.version,
+                request.include_prerelease.unwrap_or(false),
+            ) {
+                Ok(registration) => ResolveResult {
+                    registration: Some(registration),
+                    error: None,
+                },
+                Err(err) => ResolveResult {
+                    registration: None,
+                    error: Some(err.to_string()),
+                },
+            }
+        })
+        .collect();
+
+    to_binary(&ResolveManyResponse { results })
+}
+
+/// Follows aliases from a name to the canonical name it redirects to.
+pub fn resolve_name(deps: Deps, name: String, chain_id: String) -> StdResult<String> {
+    let mut name = name;
+    while let Some(target) =
+        NAME_CHAIN_ID_TO_ALIAS_TARGET.may_load(deps.storage, (name.clone(), chain_id.clone()))?
+    {
+        name = target;
+    }
+    Ok(name)
 }
 
-pub fn query_get_registration(
+/// Loads the given version's registration, or the latest if no version is
+/// provided. Pre-releases are only considered latest if included.
+pub fn load_registration(
     deps: Deps,
     name: String,
     chain_id: String,
     version: Option<String>,
-) -> StdResult<Binary> {
-    let registration = if let Some(version) = version {
-        // Get specific version if passed.
+    include_prerelease: bool,
+) -> StdResult<Registration> {
+    if let Some(version) = version {
+        // Get specific version if passed, distinguishing removed versions.
         let code_id = NAME_CHAIN_ID_VERSION_TO_CODE_ID
-            .load(deps.storage, (name.clone(), chain_id.clone(), version))
-            .map_err(|_| StdError::GenericErr {
-                msg: ContractError::NotFound {}.to_string(),
+            .may_load(
+                deps.storage,
+                (name.clone(), chain_id.clone(), version.clone()),
+            )?
+            .ok_or_else(|| {
+                let err = if NAME_CHAIN_ID_VERSION_TO_TOMBSTONE.has(
+                    deps.storage,
+                    (name.clone(), chain_id.clone(), version.clone()),
+                ) {
+                    ContractError::VersionRemoved(version, name.clone(), chain_id.clone())
+                } else {
+                    ContractError::NotFound {}
+                };
+                StdError::GenericErr {
+                    msg: err.to_string(),
+                }
             })?;
 
         NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
             .load(deps.storage, (name, chain_id, code_id))
             .map_err(|_| StdError::GenericErr {
                 msg: ContractError::NotFound {}.to_string(),
-            })?
+            })
     } else {
-        // Get most recent code ID registration.
-        NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        // Get most recent code ID registration that has not been yanked.
+        Ok(NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
             .prefix((name, chain_id))
             .range(deps.storage, None, None, Order::Descending)
-            .next()
+            .find(|item| {
+                item.as_ref().map_or(true, |(_, registration)| {
+                    !registration.yanked && (include_prerelease || !registration.prerelease)
+                })
+            })
             .ok_or(StdError::GenericErr {
                 msg: ContractError::NotFound {}.to_string(),
             })?
             .map_err(|_| StdError::GenericErr {
                 msg: ContractError::NotFound {}.to_string(),
             })?
-            .1
-    };
-    to_binary(&GetRegistrationResponse { registration })
+            .1)
+    }
 }
 
-pub fn query_info_for_code_id(deps: Deps, chain_id: String, code_id: u64) -> StdResult<Binary> {
-    // Retrieve contract name.
-    let name = CHAIN_ID_CODE_ID_TO_NAME
-        .load(deps.storage, (chain_id.clone(), code_id))
+pub fn query_get_tagged_registration(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    tag: String,
+) -> StdResult<Binary> {
+    let canonical_name = resolve_name(deps, name.clone(), chain_id.clone())?;
+    let version = NAME_CHAIN_ID_TAG_TO_VERSION
+        .load(
+            deps.storage,
+            (canonical_name.clone(), chain_id.clone(), tag),
+        )
         .map_err(|_| StdError::GenericErr {
             msg: ContractError::NotFound {}.to_string(),
         })?;
+    let registration =
+        load_registration(deps, canonical_name.clone(), chain_id, Some(version), true)?;
+    to_binary(&GetRegistrationResponse {
+        redirected_from: (canonical_name != name).then_some(name),
+        name: canonical_name,
+        registration,
+        source: None,
+    })
+}
+
+pub fn query_list_tags(deps: Deps, name: String, chain_id: String) -> StdResult<Binary> {
+    let name = resolve_name(deps, name, chain_id.clone())?;
+    let tags = NAME_CHAIN_ID_TAG_TO_VERSION
+        .prefix((name, chain_id))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, String)>>>()?;
+    to_binary(&ListTagsResponse { tags })
+}
+
+pub fn query_info_for_code_id(deps: Deps, chain_id: String, code_id: u64) -> StdResult<Binary> {
+    // Retrieve contract name, looking in each upstream in turn if the code ID
+    // is not registered here.
+    let name = match CHAIN_ID_CODE_ID_TO_NAME.may_load(deps.storage, (chain_id.clone(), code_id))? {
+        Some(name) => name,
+        None => {
+            if CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME.has(deps.storage, (chain_id.clone(), code_id)) {
+                return Err(StdError::GenericErr {
+                    msg: ContractError::CodeIDRemoved(code_id, chain_id).to_string(),
+                });
+            }
+            let msg = QueryMsg::InfoForCodeId {
+                chain_id: chain_id.clone(),
+                code_id,
+            };
+            for upstream in CONFIG.load(deps.storage)?.upstreams {
+                if let Ok(mut res) = deps
+                    .querier
+                    .query_wasm_smart::<InfoForCodeIdResponse>(&upstream, &msg)
+                {
+                    res.source = res.source.or(Some(upstream));
+                    return to_binary(&res);
+                }
+            }
+            return Err(StdError::GenericErr {
+                msg: ContractError::NotFound {}.to_string(),
+            });
+        }
+    };
 
     // Retrieve registration.
     let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
@@ -469,17 +2905,666 @@ pub fn query_info_for_code_id(deps: Deps, chain_id: String, code_id: u64) -> Std
         name,
         version: registration.version,
         checksum: registration.checksum,
+        source: None,
     })
 }
 
 // TODO: Paginate.
-pub fn query_list_registrations(deps: Deps, name: String, chain_id: String) -> StdResult<Binary> {
+pub fn query_list_registrations(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    include_prerelease: bool,
+) -> StdResult<Binary> {
+    let name = resolve_name(deps, name, chain_id.clone())?;
     let registrations = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
         .prefix((name, chain_id))
         .range(deps.storage, None, None, Order::Ascending)
         .collect::<StdResult<Vec<(u64, Registration)>>>()?
         .into_iter()
         .map(|(_, registration)| registration)
+        .filter(|registration| {
+            !registration.yanked && (include_prerelease || !registration.prerelease)
+        })
         .collect();
     to_binary(&ListRegistrationsResponse { registrations })
 }
+
+pub fn query_get_fee(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    sender: String,
+) -> StdResult<Binary> {
+    let name = resolve_name(deps, name, chain_id.clone())?;
+    let config = CONFIG.load(deps.storage)?;
+    let sender = deps.api.addr_validate(&sender)?;
+    let payment_info = effective_payment_info(deps, &config, &name, &chain_id, &sender)?;
+    to_binary(&GetFeeResponse { payment_info })
+}
+
+pub fn query_pause_status(deps: Deps, env: Env) -> StdResult<Binary> {
+    let until = PAUSED_UNTIL
+        .may_load(deps.storage)?
+        .filter(|until| !until.is_expired(&env.block));
+    to_binary(&PauseStatusResponse {
+        paused: until.is_some(),
+        until,
+    })
+}
+
+pub fn query_list_registrations_by_registrant(
+    deps: Deps,
+    address: String,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let registrations = REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME
+        .sub_prefix(address)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let ((chain_id, code_id), name) = item?;
+            let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                .load(deps.storage, (name.clone(), chain_id.clone(), code_id))?;
+            Ok(RegistrantEntry {
+                name,
+                chain_id,
+                registration,
+            })
+        })
+        .collect::<StdResult<Vec<RegistrantEntry>>>()?;
+
+    to_binary(&ListRegistrationsByRegistrantResponse { registrations })
+}
+
+pub fn query_get_nonce(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let nonce = SIGNER_NONCE
+        .may_load(deps.storage, address)?
+        .unwrap_or_default();
+    to_binary(&GetNonceResponse { nonce })
+}
+
+pub fn query_list_hooks(
+    deps: Deps,
+    name: Option<String>,
+    chain_id: Option<String>,
+) -> StdResult<Binary> {
+    let package = resolve_package(deps, name, chain_id).map_err(|err| StdError::GenericErr {
+        msg: err.to_string(),
+    })?;
+    let hooks = match package {
+        Some(package) => NAME_CHAIN_ID_HOOK
+            .prefix(package)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?,
+        None => GLOBAL_HOOKS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?,
+    };
+    to_binary(&ListHooksResponse { hooks })
+}
+
+pub fn query_reverse_dependencies(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    version: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let name = resolve_name(deps, name, chain_id.clone())?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let version = version.map(|version| parse_version(&version));
+
+    let dependents = DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME
+        .prefix((name.clone(), chain_id.clone()))
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (code_id, dependent) = item?;
+            let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                .load(deps.storage, (dependent.clone(), chain_id.clone(), code_id))?;
+            // Dependencies may have been declared under a name that is now an
+            // alias.
+            let mut version_req = String::new();
+            for dependency in &registration.dependencies {
+                if resolve_name(deps, dependency.name.clone(), chain_id.clone())? == name {
+                    version_req = dependency.version_req.clone();
+                }
+            }
+            Ok(ReverseDependency {
+                name: dependent,
+                registration,
+                version_req,
+            })
+        })
+        .filter(|item| match (item, &version) {
+            (Ok(dependent), Some(version)) => version.as_ref().is_some_and(|version| {
+                parse_version_req(&dependent.version_req).is_some_and(|req| req.matches(version))
+            }),
+            _ => true,
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<ReverseDependency>>>()?;
+
+    to_binary(&ReverseDependenciesResponse { dependents })
+}
+
+pub fn query_migration_path(
+    deps: Deps,
+    chain_id: String,
+    from_code_id: u64,
+    to_version: Option<String>,
+) -> StdResult<Binary> {
+    let name = CHAIN_ID_CODE_ID_TO_NAME
+        .load(deps.storage, (chain_id.clone(), from_code_id))
+        .map_err(|_| StdError::GenericErr {
+            msg: ContractError::NotFound {}.to_string(),
+        })?;
+    let target = load_registration(deps, name, chain_id.clone(), to_version, false)?;
+
+    // Breadth first search for the shortest path, remembering the hop used
+    // to first reach each code ID.
+    let mut reached: HashMap<u64, MigrationHop> = HashMap::new();
+    let mut queue = VecDeque::from([from_code_id]);
+    while let Some(code_id) = queue.pop_front() {
+        if code_id == target.code_id {
+            break;
+        }
+        let migrations = CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION
+            .prefix((chain_id.clone(), code_id))
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(u64, Migration)>>>()?;
+        for (to_code_id, migration) in migrations {
+            if to_code_id == from_code_id || reached.contains_key(&to_code_id) {
+                continue;
+            }
+            let to_name =
+                CHAIN_ID_CODE_ID_TO_NAME.load(deps.storage, (chain_id.clone(), to_code_id))?;
+            let to_registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+                .load(deps.storage, (to_name, chain_id.clone(), to_code_id))?;
+            reached.insert(
+                to_code_id,
+                MigrationHop {
+                    from_code_id: code_id,
+                    to_code_id,
+                    to_version: to_registration.version,
+                    migration,
+                },
+            );
+            queue.push_back(to_code_id);
+        }
+    }
+
+    // Walk back from the target to build the path.
+    let mut hops = vec![];
+    let mut code_id = target.code_id;
+    while code_id != from_code_id {
+        let hop = reached.remove(&code_id).ok_or(StdError::GenericErr {
+            msg: ContractError::NoMigrationPath {}.to_string(),
+        })?;
+        code_id = hop.from_code_id;
+        hops.push(hop);
+    }
+    hops.reverse();
+
+    to_binary(&MigrationPathResponse { hops })
+}
+
+pub fn query_identify_contract(deps: Deps, env: Env, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let contract_info: ContractInfoResponse =
+        deps.querier
+            .query(&QueryRequest::Wasm(WasmQuery::ContractInfo {
+                contract_addr: address.to_string(),
+            }))?;
+    // Contracts are not required to store cw2 info.
+    let contract_version: Option<ContractVersion> = deps
+        .querier
+        .query_wasm_raw(&address, CONTRACT.as_slice())?
+        .and_then(|data| from_slice(&data).ok());
+
+    let chain_id = env.block.chain_id;
+    let code_id = contract_info.code_id;
+    let name = CHAIN_ID_CODE_ID_TO_NAME
+        .load(deps.storage, (chain_id.clone(), code_id))
+        .map_err(|_| StdError::GenericErr {
+            msg: ContractError::NotFound {}.to_string(),
+        })?;
+    let registration = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+        .load(deps.storage, (name.clone(), chain_id, code_id))?;
+
+    // cw2 names are usually prefixed with their source, like `crates.io:`,
+    // and versions lack the `v` registrations often have.
+    let (name_mismatch, version_mismatch) = match &contract_version {
+        Some(version) => (
+            version.contract.rsplit(':').next() != Some(name.as_str()),
+            !same_version(&version.version, &registration.version),
+        ),
+        None => (false, false),
+    };
+
+    to_binary(&IdentifyContractResponse {
+        code_id,
+        name,
+        registration,
+        contract_version,
+        name_mismatch,
+        version_mismatch,
+    })
+}
+
+pub fn query_get_chain(deps: Deps, chain_id: String) -> StdResult<Binary> {
+    // Resolve aliases to the canonical chain ID.
+    let chain_id = CHAIN_ALIAS_TO_CHAIN_ID
+        .may_load(deps.storage, chain_id.clone())?
+        .unwrap_or(chain_id);
+    let chain = CHAINS
+        .load(deps.storage, chain_id)
+        .map_err(|_| StdError::GenericErr {
+            msg: ContractError::NotFound {}.to_string(),
+        })?;
+    to_binary(&GetChainResponse { chain })
+}
+
+pub fn query_get_tombstone(
+    deps: Deps,
+    name: String,
+    chain_id: String,
+    version: String,
+) -> StdResult<Binary> {
+    let name = resolve_name(deps, name, chain_id.clone())?;
+    let tombstone = NAME_CHAIN_ID_VERSION_TO_TOMBSTONE
+        .load(deps.storage, (name, chain_id, version))
+        .map_err(|_| StdError::GenericErr {
+            msg: ContractError::NotFound {}.to_string(),
+        })?;
+    to_binary(&tombstone)
+}
+
+pub fn query_list_chains(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let chains = CHAINS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, chain)| chain))
+        .collect::<StdResult<Vec<Chain>>>()?;
+    to_binary(&ListChainsResponse { chains })
+}
+
+pub fn query_list_ibc_channels(deps: Deps) -> StdResult<Binary> {
+    let channels = IBC_CHANNEL_ID_TO_CONNECTION_ID
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (channel_id, connection_id) = item?;
+            Ok(IbcChannelEntry {
+                chain_id: IBC_CONNECTION_ID_TO_CHAIN_ID
+                    .may_load(deps.storage, connection_id.clone())?,
+                channel_id,
+                connection_id,
+            })
+        })
+        .collect::<StdResult<Vec<IbcChannelEntry>>>()?;
+
+    to_binary(&ListIbcChannelsResponse { channels })
+}
+
+/// Exports `map` from `start`, wrapping each item with `entry`.
+fn export_range<'a, K, T>(
+    deps: Deps,
+    map: Map<'a, K, T>,
+    start: Option<Bound<'a, K>>,
+    limit: usize,
+    entry: impl Fn(K::Output, T) -> ExportEntry,
+) -> StdResult<Vec<ExportEntry>>
+where
+    K: PrimaryKey<'a> + KeyDeserialize,
+    K::Output: 'static,
+    T: Serialize + DeserializeOwned,
+{
+    map.range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(key, value)| entry(key, value)))
+        .collect()
+}
+
+pub fn query_export(
+    deps: Deps,
+    start_after: Option<ExportKey>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // Each kind of entry is exported in key order, in the order of the
+    // `ExportEntry` variants. Kinds before the one start_after belongs to
+    // are skipped.
+    let mut started = start_after.is_none();
+    let mut entries = vec![];
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Chain { chain_id }) => Some(Bound::exclusive(chain_id)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(deps, CHAINS, start, limit, |_, chain| {
+            ExportEntry::Chain { chain }
+        })?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Alias { alias, chain_id }) => Some(Bound::exclusive((alias, chain_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_TO_ALIAS_TARGET,
+            start,
+            limit - entries.len(),
+            |(alias, chain_id), name| ExportEntry::Alias {
+                alias,
+                chain_id,
+                name,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Registration {
+            name,
+            chain_id,
+            code_id,
+        }) => Some(Bound::exclusive((name, chain_id, code_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, _), registration| ExportEntry::Registration {
+                name,
+                chain_id,
+                registration,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Tombstone {
+            name,
+            chain_id,
+            version,
+        }) => Some(Bound::exclusive((name, chain_id, version))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_VERSION_TO_TOMBSTONE,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, _), tombstone| ExportEntry::Tombstone {
+                name,
+                chain_id,
+                tombstone,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Owner { name, chain_id }) => Some(Bound::exclusive((name, chain_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_TO_OWNER,
+            start,
+            limit - entries.len(),
+            |(name, chain_id), owner| ExportEntry::Owner {
+                name,
+                chain_id,
+                owner,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Tag {
+            name,
+            chain_id,
+            tag,
+        }) => Some(Bound::exclusive((name, chain_id, tag))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_TAG_TO_VERSION,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, tag), version| ExportEntry::Tag {
+                name,
+                chain_id,
+                tag,
+                version,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Migration {
+            chain_id,
+            from_code_id,
+            to_code_id,
+        }) => Some(Bound::exclusive((chain_id, from_code_id, to_code_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION,
+            start,
+            limit - entries.len(),
+            |(chain_id, from_code_id, to_code_id), migration| ExportEntry::Migration {
+                chain_id,
+                from_code_id,
+                to_code_id,
+                migration,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Deposit {
+            name,
+            chain_id,
+            code_id,
+        }) => Some(Bound::exclusive((name, chain_id, code_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, code_id), deposit| ExportEntry::Deposit {
+                name,
+                chain_id,
+                code_id,
+                deposit,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::GracePeriod {
+            name,
+            chain_id,
+            code_id,
+        }) => Some(Bound::exclusive((name, chain_id, code_id))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, code_id), grace_period| ExportEntry::GracePeriod {
+                name,
+                chain_id,
+                code_id,
+                grace_period,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::FeeExemption { address }) => Some(Bound::exclusive(address)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            FEE_EXEMPT_ADDRESSES,
+            start,
+            limit - entries.len(),
+            |address, _| ExportEntry::FeeExemption { address },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::NameFee { name }) => Some(Bound::exclusive(name)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_TO_PAYMENT_INFO,
+            start,
+            limit - entries.len(),
+            |name, payment_info| ExportEntry::NameFee { name, payment_info },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::ChainIdFee { chain_id }) => Some(Bound::exclusive(chain_id)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            CHAIN_ID_TO_PAYMENT_INFO,
+            start,
+            limit - entries.len(),
+            |chain_id, payment_info| ExportEntry::ChainIdFee {
+                chain_id,
+                payment_info,
+            },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::GlobalHook { hook }) => Some(Bound::exclusive(hook)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            GLOBAL_HOOKS,
+            start,
+            limit - entries.len(),
+            |hook, _| ExportEntry::GlobalHook { hook },
+        )?);
+    }
+
+    let start = match start_after.clone() {
+        Some(ExportKey::Hook {
+            name,
+            chain_id,
+            hook,
+        }) => Some(Bound::exclusive((name, chain_id, hook))),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            NAME_CHAIN_ID_HOOK,
+            start,
+            limit - entries.len(),
+            |(name, chain_id, hook), _| ExportEntry::Hook {
+                name,
+                chain_id,
+                hook,
+            },
+        )?);
+    }
+
+    let start = match start_after {
+        Some(ExportKey::IbcConnection { connection_id }) => Some(Bound::exclusive(connection_id)),
+        _ => None,
+    };
+    started |= start.is_some();
+    if started {
+        entries.extend(export_range(
+            deps,
+            IBC_CONNECTION_ID_TO_CHAIN_ID,
+            start,
+            limit - entries.len(),
+            |connection_id, chain_id| ExportEntry::IbcConnection {
+                connection_id,
+                chain_id,
+            },
+        )?);
+    }
+
+    // Config is a single entry, exported last.
+    if started && entries.len() < limit {
+        entries.push(ExportEntry::Config {
+            config: CONFIG.load(deps.storage)?,
+        });
+    }
+
+    to_binary(&ExportResponse { entries })
+}
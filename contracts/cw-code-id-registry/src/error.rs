@@ -1,5 +1,5 @@
-use cosmwasm_std::StdError;
-use cw_utils::PaymentError;
+use cosmwasm_std::{StdError, Uint128};
+use cw_utils::{Expiration, ParseReplyError, PaymentError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,6 +11,9 @@ pub enum ContractError {
     #[error("{0}")]
     Payment(#[from] PaymentError),
 
+    #[error("{0}")]
+    ParseReply(#[from] ParseReplyError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -29,12 +32,114 @@ pub enum ContractError {
     #[error("Invalid CW20, this address is not a CW20")]
     InvalidCw20 {},
 
-    #[error("This CW20's address does not match the configured CW20 payment address")]
+    #[error("This CW20's address does not match any configured CW20 payment address")]
     UnrecognizedCw20 {},
 
-    #[error("This token's denom does not match the configured token's denom")]
+    #[error("This token's denom does not match any configured token's denom")]
     UnrecognizedNativeToken {},
 
-    #[error("Invalid payment")]
-    InvalidPayment {},
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("Invalid nonce, expected {expected}")]
+    InvalidNonce { expected: u64 },
+
+    #[error("Funds sent do not match the funds to instantiate with")]
+    FundsMismatch {},
+
+    #[error("Unknown reply ID {0}")]
+    UnknownReplyId(u64),
+
+    #[error("Both a name and chain ID are needed to refer to a package")]
+    IncompletePackage {},
+
+    #[error("Invalid version requirement {0}")]
+    InvalidVersionReq(String),
+
+    #[error("No registration of {0} matches {1}")]
+    UnsatisfiedDependency(String, String),
+
+    #[error("Cannot migrate a version to itself")]
+    InvalidMigration {},
+
+    #[error("No migration path found")]
+    NoMigrationPath {},
+
+    #[error("Batch size exceeds the maximum of {0}")]
+    BatchTooLarge(usize),
+
+    #[error("Chain ID {0} is not in the chain catalogue")]
+    UnknownChainId(String),
+
+    #[error("Chain ID {0} is an alias, use the canonical chain ID {1}")]
+    NonCanonicalChainId(String, String),
+
+    #[error("Chain ID {0} is already in use")]
+    ChainIdInUse(String),
+
+    #[error("Invalid chain")]
+    InvalidChain {},
+
+    #[error("Name {0} is an alias")]
+    NameIsAlias(String),
+
+    #[error("Name {0} is already in use")]
+    NameInUse(String),
+
+    #[error("Invalid tag {0}")]
+    InvalidTag(String),
+
+    #[error("Contract is paused")]
+    Paused {},
+
+    #[error("Pause expiration must be in the future")]
+    InvalidPauseExpiration {},
+
+    #[error("Fee recipient shares must be non-zero and sum to one")]
+    InvalidFeeShares {},
+
+    #[error("Payment token {0} is configured more than once")]
+    DuplicatePaymentToken(String),
+
+    #[error("Imports have been sealed")]
+    ImportSealed {},
+
+    #[error("Invalid upstream registry {0}")]
+    InvalidUpstream(String),
+
+    #[error("IBC channels must be unordered and use version {0}")]
+    InvalidIbcChannel(String),
+
+    #[error("IBC connection {0} is not trusted")]
+    UntrustedConnection(String),
+
+    #[error("Unknown IBC channel {0}")]
+    UnknownChannel(String),
+
+    #[error("Changes for chain ID {0} are only accepted from that chain")]
+    UntrustedPacketSource(String),
+
+    #[error("The unregister window has expired, yank instead")]
+    UnregisterWindowExpired {},
+
+    #[error("The unregister window has not expired")]
+    UnregisterWindowOpen {},
+
+    #[error("Version {0} of {1} on {2} was removed")]
+    VersionRemoved(String, String, String),
+
+    #[error("Code ID {0} on {1} was removed")]
+    CodeIDRemoved(u64, String),
+
+    #[error("Registration limit reached until {0}")]
+    RateLimited(Expiration),
+
+    #[error("Rate limits must allow at least one registration")]
+    InvalidRateLimit {},
+
+    #[error("A deposit of {0} {1} is required")]
+    DepositRequired(Uint128, String),
+
+    #[error("The deposit was returned when this version was yanked")]
+    DepositReturned {},
 }
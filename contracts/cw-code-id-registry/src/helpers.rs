@@ -0,0 +1,161 @@
+use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::{
+    ExecuteMsg, GetRegistrationResponse, InfoForCodeIdResponse, QueryMsg, ReceiveMsg,
+};
+
+/// CodeIdRegistryContract is a wrapper around Addr that provides helpers for
+/// contracts looking up code IDs in, or registering with, the registry.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct CodeIdRegistryContract(pub Addr);
+
+impl CodeIdRegistryContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<ExecuteMsg>>(&self, msg: T, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into(),
+            msg: to_binary(&msg.into())?,
+            funds,
+        }
+        .into())
+    }
+
+    /// Register a code ID, paying the fee in native tokens with `funds`.
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        version: impl Into<String>,
+        chain_id: impl Into<String>,
+        code_id: u64,
+        checksum: impl Into<String>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::Register {
+                name: name.into(),
+                version: version.into(),
+                chain_id: chain_id.into(),
+                code_id,
+                checksum: checksum.into(),
+                dependencies: None,
+            },
+            funds,
+        )
+    }
+
+    /// Send `amount` of the CW20 `token` to the registry with `msg`, paying
+    /// the fee for the registration it describes. Use this for registrations
+    /// paid in CW20 tokens rather than `call`.
+    pub fn call_cw20(&self, token: Addr, amount: Uint128, msg: ReceiveMsg) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: token.into(),
+            msg: to_binary(&Cw20ExecuteMsg::Send {
+                contract: self.addr().into(),
+                amount,
+                msg: to_binary(&msg)?,
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    pub fn set_owner(
+        &self,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        owner: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::SetOwner {
+                name: name.into(),
+                chain_id: chain_id.into(),
+                owner,
+            },
+            vec![],
+        )
+    }
+
+    pub fn unregister(
+        &self,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        code_id: u64,
+    ) -> StdResult<CosmosMsg> {
+        self.call(
+            ExecuteMsg::Unregister {
+                name: name.into(),
+                chain_id: chain_id.into(),
+                code_id,
+                reason: None,
+            },
+            vec![],
+        )
+    }
+
+    /// Get the registration for `version`, or the latest non-prerelease
+    /// registration if `version` is None.
+    pub fn registration(
+        &self,
+        querier: &QuerierWrapper,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        version: Option<String>,
+    ) -> StdResult<GetRegistrationResponse> {
+        querier.query_wasm_smart(
+            self.addr(),
+            &QueryMsg::GetRegistration {
+                name: name.into(),
+                chain_id: chain_id.into(),
+                version,
+                include_prerelease: None,
+            },
+        )
+    }
+
+    /// Get the code ID of the latest non-prerelease version.
+    pub fn latest_code_id(
+        &self,
+        querier: &QuerierWrapper,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+    ) -> StdResult<u64> {
+        Ok(self
+            .registration(querier, name, chain_id, None)?
+            .registration
+            .code_id)
+    }
+
+    pub fn code_id_for_version(
+        &self,
+        querier: &QuerierWrapper,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        version: impl Into<String>,
+    ) -> StdResult<u64> {
+        Ok(self
+            .registration(querier, name, chain_id, Some(version.into()))?
+            .registration
+            .code_id)
+    }
+
+    pub fn info_for_code_id(
+        &self,
+        querier: &QuerierWrapper,
+        chain_id: impl Into<String>,
+        code_id: u64,
+    ) -> StdResult<InfoForCodeIdResponse> {
+        querier.query_wasm_smart(
+            self.addr(),
+            &QueryMsg::InfoForCodeId {
+                chain_id: chain_id.into(),
+                code_id,
+            },
+        )
+    }
+}
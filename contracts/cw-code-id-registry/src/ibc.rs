@@ -0,0 +1,330 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcMsg, IbcOrder, IbcPacket,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Order,
+    StdResult,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::contract::{is_paused, save_external_registration, set_yanked};
+use crate::error::ContractError;
+use crate::state::{
+    Registration, IBC_CHANNEL_ID_TO_CONNECTION_ID, IBC_CONNECTION_ID_TO_CHAIN_ID,
+    NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION, NAME_CHAIN_ID_TO_OWNER,
+};
+
+pub const IBC_VERSION: &str = "cw-code-id-registry-1";
+pub const IBC_ORDERING: IbcOrder = IbcOrder::Unordered;
+/// Seconds until a packet to another registry times out.
+pub const PACKET_LIFETIME: u64 = 60 * 60;
+
+/// Changes sent from the registry on the chain they are for to registries on
+/// other chains.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum RegistryPacket {
+    Register {
+        name: String,
+        chain_id: String,
+        registration: Registration,
+    },
+    SetOwner {
+        name: String,
+        chain_id: String,
+        owner: Option<Addr>,
+    },
+    Yank {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+        yanked: bool,
+    },
+}
+
+impl RegistryPacket {
+    pub fn chain_id(&self) -> &str {
+        match self {
+            RegistryPacket::Register { chain_id, .. }
+            | RegistryPacket::SetOwner { chain_id, .. }
+            | RegistryPacket::Yank { chain_id, .. } => chain_id,
+        }
+    }
+}
+
+/// The acknowledgement written for a received packet.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegistryAck {
+    Result(Binary),
+    Error(String),
+}
+
+fn ack_success() -> Binary {
+    to_binary(&RegistryAck::Result(b"1".into())).unwrap()
+}
+
+fn ack_fail(err: String) -> Binary {
+    to_binary(&RegistryAck::Error(err)).unwrap()
+}
+
+/// Messages sending `packet` to every connected registry, if it is for a
+/// change on this chain. Changes for other chains are only synced by the
+/// registries on those chains.
+pub fn broadcast_msgs(deps: Deps, env: &Env, packet: &RegistryPacket) -> StdResult<Vec<CosmosMsg>> {
+    if packet.chain_id() != env.block.chain_id {
+        return Ok(vec![]);
+    }
+    let data = to_binary(packet)?;
+    IBC_CHANNEL_ID_TO_CONNECTION_ID
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|channel_id| {
+            Ok(IbcMsg::SendPacket {
+                channel_id: channel_id?,
+                data: data.clone(),
+                timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+            }
+            .into())
+        })
+        .collect()
+}
+
+fn validate_channel(
+    deps: Deps,
+    order: &IbcOrder,
+    version: &str,
+    counterparty_version: Option<&str>,
+    connection_id: &str,
+) -> Result<(), ContractError> {
+    if *order != IBC_ORDERING
+        || version != IBC_VERSION
+        || counterparty_version.is_some_and(|version| version != IBC_VERSION)
+    {
+        return Err(ContractError::InvalidIbcChannel(IBC_VERSION.to_string()));
+    }
+    // Only connections to chains the admin trusts may be used.
+    if !IBC_CONNECTION_ID_TO_CHAIN_ID.has(deps.storage, connection_id.to_string()) {
+        return Err(ContractError::UntrustedConnection(
+            connection_id.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    let channel = msg.channel();
+    validate_channel(
+        deps.as_ref(),
+        &channel.order,
+        &channel.version,
+        msg.counterparty_version(),
+        &channel.connection_id,
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_channel(
+        deps.as_ref(),
+        &channel.order,
+        &channel.version,
+        msg.counterparty_version(),
+        &channel.connection_id,
+    )?;
+
+    IBC_CHANNEL_ID_TO_CONNECTION_ID.save(
+        deps.storage,
+        channel.endpoint.channel_id.clone(),
+        &channel.connection_id,
+    )?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    IBC_CHANNEL_ID_TO_CONNECTION_ID.remove(deps.storage, channel.endpoint.channel_id.clone());
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_close")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    // An error acknowledgement is final, so while paused fail without one
+    // and leave relayers to retry the packet once unpaused.
+    if is_paused(deps.as_ref(), &env)? {
+        return Err(ContractError::Paused {});
+    }
+
+    // Other failures are acknowledged as errors so the sender learns of them.
+    receive_packet(deps, msg.packet).or_else(|err| {
+        Ok(IbcReceiveResponse::new()
+            .set_ack(ack_fail(err.to_string()))
+            .add_attribute("action", "receive_packet")
+            .add_attribute("error", err.to_string()))
+    })
+}
+
+fn receive_packet(deps: DepsMut, packet: IbcPacket) -> Result<IbcReceiveResponse, ContractError> {
+    let channel_id = packet.dest.channel_id;
+    let connection_id = IBC_CHANNEL_ID_TO_CONNECTION_ID
+        .may_load(deps.storage, channel_id.clone())?
+        .ok_or(ContractError::UnknownChannel(channel_id))?;
+    let source_chain_id = IBC_CONNECTION_ID_TO_CHAIN_ID
+        .may_load(deps.storage, connection_id.clone())?
+        .ok_or(ContractError::UntrustedConnection(connection_id))?;
+
+    // Only the registry on the chain a change is for is trusted with it.
+    let msg: RegistryPacket = from_binary(&packet.data)?;
+    if msg.chain_id() != source_chain_id {
+        return Err(ContractError::UntrustedPacketSource(
+            msg.chain_id().to_string(),
+        ));
+    }
+
+    match msg {
+        RegistryPacket::Register {
+            name,
+            chain_id,
+            registration,
+        } => {
+            // Packets may be relayed more than once.
+            let existing = NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION.may_load(
+                deps.storage,
+                (name.clone(), chain_id.clone(), registration.code_id),
+            )?;
+            if existing.as_ref() != Some(&registration) {
+                save_external_registration(deps, &name, &chain_id, &registration)?;
+            }
+        }
+        RegistryPacket::SetOwner {
+            name,
+            chain_id,
+            owner,
+        } => match owner {
+            Some(owner) => NAME_CHAIN_ID_TO_OWNER.save(deps.storage, (name, chain_id), &owner)?,
+            None => NAME_CHAIN_ID_TO_OWNER.remove(deps.storage, (name, chain_id)),
+        },
+        RegistryPacket::Yank {
+            name,
+            chain_id,
+            code_id,
+            yanked,
+        } => set_yanked(deps.storage, &name, &chain_id, code_id, yanked)?,
+    }
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(ack_success())
+        .add_attribute("action", "receive_packet")
+        .add_attribute("chain_id", source_chain_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let res = IbcBasicResponse::new()
+        .add_attribute("action", "acknowledge")
+        .add_attribute("channel_id", msg.original_packet.src.channel_id);
+    Ok(match from_binary(&msg.acknowledgement.data)? {
+        RegistryAck::Result(_) => res.add_attribute("success", "true"),
+        RegistryAck::Error(err) => res
+            .add_attribute("success", "false")
+            .add_attribute("error", err),
+    })
+}
+
+/// The packet syncing the current state of what `packet` changed, or None
+/// if it no longer exists here. Resending current state rather than the
+/// original change keeps a stale change from overwriting a later one.
+fn current_packet(deps: Deps, packet: RegistryPacket) -> StdResult<Option<RegistryPacket>> {
+    Ok(match packet {
+        RegistryPacket::Register {
+            name,
+            chain_id,
+            registration,
+        } => NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+            .may_load(
+                deps.storage,
+                (name.clone(), chain_id.clone(), registration.code_id),
+            )?
+            .map(|registration| RegistryPacket::Register {
+                name,
+                chain_id,
+                registration,
+            }),
+        RegistryPacket::SetOwner { name, chain_id, .. } => Some(RegistryPacket::SetOwner {
+            owner: NAME_CHAIN_ID_TO_OWNER
+                .may_load(deps.storage, (name.clone(), chain_id.clone()))?,
+            name,
+            chain_id,
+        }),
+        RegistryPacket::Yank {
+            name,
+            chain_id,
+            code_id,
+            ..
+        } => NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION
+            .may_load(deps.storage, (name.clone(), chain_id.clone(), code_id))?
+            .map(|registration| RegistryPacket::Yank {
+                name,
+                chain_id,
+                code_id,
+                yanked: registration.yanked,
+            }),
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel_id = msg.packet.src.channel_id;
+    let res = IbcBasicResponse::new()
+        .add_attribute("action", "timeout")
+        .add_attribute("channel_id", &channel_id);
+
+    // Resend the change while the channel is open so the other registry
+    // does not silently miss it.
+    if !IBC_CHANNEL_ID_TO_CONNECTION_ID.has(deps.storage, channel_id.clone()) {
+        return Ok(res);
+    }
+    let packet = match current_packet(deps.as_ref(), from_binary(&msg.packet.data)?)? {
+        Some(packet) => packet,
+        None => return Ok(res),
+    };
+    Ok(res.add_message(IbcMsg::SendPacket {
+        channel_id,
+        data: to_binary(&packet)?,
+        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+    }))
+}
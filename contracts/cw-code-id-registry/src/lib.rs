@@ -1,7 +1,12 @@
 pub mod contract;
 mod error;
+pub mod helpers;
+pub mod ibc;
 pub mod msg;
+#[cfg(any(test, feature = "multitest"))]
+pub mod multitest;
 pub mod state;
+pub mod version;
 
 #[cfg(test)]
 mod tests;
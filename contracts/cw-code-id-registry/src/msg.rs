@@ -1,54 +1,287 @@
-use crate::state::{PaymentInfo, Registration};
-use cosmwasm_std::Addr;
+use crate::state::{
+    Chain, Config, Dependency, Deposit, GracePeriod, Migration, PaymentInfo, RateLimit,
+    Registration, Tombstone,
+};
+use cosmwasm_std::{to_binary, Addr, Binary, Coin, CosmosMsg, Decimal, StdResult, WasmMsg};
+use cw2::ContractVersion;
 use cw20::Cw20ReceiveMsg;
+use cw_utils::{Duration, Expiration};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct SignedRegistration {
+    pub name: String,
+    pub version: String,
+    pub chain_id: String,
+    pub code_id: u64,
+    pub checksum: String,
+    pub dependencies: Option<Vec<Dependency>>,
+}
+
+/// The payload signed for `RegisterSigned`. The signature is over the
+/// SHA-256 hash of its JSON serialization.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct RegisterSignedPayload {
+    /// Address of the registry, so signatures cannot be replayed on others.
+    pub registry: String,
+    /// Chain ID of the chain the registry is on, so signatures cannot be
+    /// replayed on a registry at the same address on another chain.
+    pub registry_chain_id: String,
+    pub nonce: u64,
+    pub registration: SignedRegistration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct InstantiatePackageMsg {
+    pub name: String,
+    pub version: Option<String>,
+    pub msg: Binary,
+    pub label: String,
+    pub admin: Option<String>,
+    /// Funds to instantiate with, which must match the funds sent.
+    pub funds: Vec<Coin>,
+}
+
+/// Maximum number of requests accepted by a single `ResolveMany` query.
+pub const MAX_RESOLVE_BATCH_SIZE: usize = 50;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub struct InstantiateMsg {
     pub admin: String,
-    pub payment_info: PaymentInfo,
+    /// Payments accepted for registering. If empty, registering is free.
+    pub payment_info: Vec<PaymentInfo>,
+    /// Addresses that fees are split between, with the share each one
+    /// receives. Defaults to sending all fees to the admin.
+    pub fee_recipients: Option<Vec<(String, Decimal)>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum ExecuteMsg {
-    /// Receive payment to register when payment info is a CW20.
+    /// Receive payment to register with one of the accepted CW20s.
     Receive(Cw20ReceiveMsg),
-    /// Receive payment to register when payment info is native.
+    /// Receive payment to register with one of the accepted native tokens.
     Register {
         name: String,
         version: String,
         chain_id: String,
         code_id: u64,
         checksum: String,
+        /// Packages this version depends on, which must have a registration
+        /// on the same chain matching the version requirement.
+        dependencies: Option<Vec<Dependency>>,
+    },
+    /// Register on behalf of an owner who signed `RegisterSignedPayload`
+    /// with their secp256k1 key. The sender relays it and pays the fee,
+    /// unless the signer is exempt from fees.
+    RegisterSigned {
+        registration: SignedRegistration,
+        pubkey: Binary,
+        signature: Binary,
+        nonce: u64,
     },
+    /// Instantiate the given version of a package, or the latest if none is
+    /// given, using its code ID on this chain. The response data contains
+    /// `InstantiatePackageResponse`.
+    InstantiatePackage(InstantiatePackageMsg),
     /// Set owner for registration.
     SetOwner {
         name: String,
         chain_id: String,
         owner: Option<String>,
     },
-    /// Allow admin to unregister code IDs.
-    Unregister { name: String, chain_id: String, code_id: u64 },
+    /// Allow admin to unregister code IDs, or the owner within the
+    /// configured window after registering.
+    Unregister {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+        /// Recorded on the tombstone in immutable mode.
+        reason: Option<String>,
+    },
     /// Update config.
     UpdateConfig {
         admin: Option<String>,
-        payment_info: Option<PaymentInfo>,
+        payment_info: Option<Vec<PaymentInfo>>,
+        fee_recipients: Option<Vec<(String, Decimal)>>,
+        validate_chain_ids: Option<bool>,
+    },
+    /// Allow admin to add and remove addresses that can register without
+    /// paying fees.
+    UpdateFeeExemptions {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Allow admin to override the payments accepted for registering a name.
+    /// An empty list makes registering the name free, and None removes the
+    /// override.
+    SetNameFee {
+        name: String,
+        payment_info: Option<Vec<PaymentInfo>>,
+    },
+    /// Allow admin to override the payments accepted for registering on a
+    /// chain. An empty list makes registering on the chain free, and None
+    /// removes the override.
+    SetChainIdFee {
+        chain_id: String,
+        payment_info: Option<Vec<PaymentInfo>>,
+    },
+    /// Allow admin to block registrations and other mutations until the
+    /// expiration, or indefinitely if none is provided.
+    Pause { until: Option<Expiration> },
+    /// Allow admin to lift a pause early.
+    Unpause {},
+    /// Point a named tag, such as `stable` or `beta`, at a registered
+    /// version. Only the admin or owner may set tags.
+    SetTag {
+        name: String,
+        chain_id: String,
+        tag: String,
+        version: String,
+    },
+    /// Remove a named tag. Only the admin or owner may remove tags.
+    RemoveTag {
+        name: String,
+        chain_id: String,
+        tag: String,
+    },
+    /// Redirect an unused alias to a name. Every query and message that
+    /// takes a name accepts the alias in its place, except registering. Only
+    /// the admin or owner of the name may add aliases.
+    SetAlias {
+        name: String,
+        chain_id: String,
+        alias: String,
+    },
+    /// Stop redirecting an alias. Only the admin or owner of the name the
+    /// alias points at may remove it.
+    RemoveAlias { alias: String, chain_id: String },
+    /// Move all registrations, tags and ownership of a name to an unused
+    /// new name, and redirect lookups of the old name to it. Only the admin
+    /// or owner may rename.
+    Rename {
+        name: String,
+        chain_id: String,
+        new_name: String,
+    },
+    /// Declare that contracts running from_version can migrate to
+    /// to_version. Only the admin or owner may declare migrations.
+    DeclareMigration {
+        name: String,
+        chain_id: String,
+        from_version: String,
+        to_version: String,
+        migrate_msg_schema_hash: Option<String>,
+        notes: Option<String>,
+    },
+    /// Remove a declared migration. Only the admin or owner may remove
+    /// migrations.
+    RemoveMigration {
+        name: String,
+        chain_id: String,
+        from_version: String,
+        to_version: String,
+    },
+    /// Add a hook contract notified of new registrations of the package, or
+    /// of all packages if no name and chain ID are given. Only the admin may
+    /// add global hooks, and the admin or owner package hooks.
+    AddHook {
+        name: Option<String>,
+        chain_id: Option<String>,
+        addr: String,
+    },
+    /// Remove a hook contract added with `AddHook`.
+    RemoveHook {
+        name: Option<String>,
+        chain_id: Option<String>,
+        addr: String,
+    },
+    /// Allow admin to set the gas each hook may use when notified.
+    SetHookGasLimit { gas_limit: u64 },
+    /// Allow admin to add or update a chain in the catalogue.
+    SetChain { chain: Chain },
+    /// Allow admin to remove a chain from the catalogue.
+    RemoveChain { chain_id: String },
+    /// Allow admin to write entries exported from another registry with
+    /// `Export`, until imports are sealed. Entries are validated like the
+    /// messages that set the same state.
+    Import { entries: Vec<ExportEntry> },
+    /// Allow admin to permanently disable `Import`.
+    SealImport {},
+    /// Allow admin to set the registries queried, in order, for names and
+    /// code IDs not registered in this one.
+    SetUpstreams { upstreams: Vec<String> },
+    /// Allow admin to trust an IBC connection to the chain with the given
+    /// chain_id, so registries there may open channels to sync changes for
+    /// that chain_id.
+    SetIbcConnection {
+        connection_id: String,
+        chain_id: String,
+    },
+    /// Allow admin to stop trusting an IBC connection.
+    RemoveIbcConnection { connection_id: String },
+    /// Allow admin to set how long after registering the owner may
+    /// unregister, and whether fees are refunded if they do.
+    SetUnregisterWindow {
+        window: Option<Duration>,
+        refund: bool,
+    },
+    /// Allow admin to set whether unregistering leaves a tombstone that
+    /// stops the version and code ID from being registered again.
+    SetImmutable { immutable: bool },
+    /// Allow admin to limit how often each registrant may register, and to
+    /// require a deposit returned when a registration is yanked or
+    /// unregistered.
+    SetRegistrationLimits {
+        rate_limit: Option<RateLimit>,
+        deposit: Option<PaymentInfo>,
+    },
+    /// Send a fee held during the unregister window to the fee recipients
+    /// once the window has expired. Anyone may call this.
+    ReleaseFee {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+    },
+    /// Allow admin or owner to mark a version as not to be used. It is
+    /// skipped when resolving the latest version.
+    Yank {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+    },
+    /// Allow admin or owner to undo a yank.
+    Unyank {
+        name: String,
+        chain_id: String,
+        code_id: u64,
     },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum ReceiveMsg {
-    // Receive payment to register when payment info is a CW20.
+    // Receive payment to register with one of the accepted CW20s.
     Register {
         name: String,
         version: String,
         chain_id: String,
         code_id: u64,
         checksum: String,
+        /// Packages this version depends on, which must have a registration
+        /// on the same chain matching the version requirement.
+        dependencies: Option<Vec<Dependency>>,
+    },
+    RegisterSigned {
+        registration: SignedRegistration,
+        pubkey: Binary,
+        signature: Binary,
+        nonce: u64,
     },
 }
 
@@ -57,26 +290,152 @@ pub enum ReceiveMsg {
 pub enum QueryMsg {
     Config {},
     /// If version provided, tries to find given version. Otherwise returns
-    /// the latest version registered.
+    /// the latest version registered, skipping pre-releases unless
+    /// include_prerelease is true.
     GetRegistration {
         name: String,
         chain_id: String,
         version: Option<String>,
+        include_prerelease: Option<bool>,
+    },
+    /// Resolves many registrations at once, as `GetRegistration` would. At
+    /// most `MAX_RESOLVE_BATCH_SIZE` requests may be passed. Results are
+    /// returned in the same order as the requests.
+    ResolveMany {
+        requests: Vec<ResolveRequest>,
     },
     InfoForCodeId {
         chain_id: String,
         code_id: u64,
     },
+    /// Pre-releases are only listed if include_prerelease is true. Yanked
+    /// versions are never listed.
     ListRegistrations {
         name: String,
         chain_id: String,
+        include_prerelease: Option<bool>,
+    },
+    /// Returns the payments accepted to register name on chain_id with
+    /// sender as the registrant, after fee exemptions and overrides are
+    /// applied. For signed registrations the registrant is the signer.
+    GetFee {
+        name: String,
+        chain_id: String,
+        sender: String,
+    },
+    PauseStatus {},
+    /// Returns the registration of the version the tag points at.
+    GetTaggedRegistration {
+        name: String,
+        chain_id: String,
+        tag: String,
     },
+    ListTags {
+        name: String,
+        chain_id: String,
+    },
+    /// Returns registrations made by the given address, ordered by chain ID
+    /// and code ID.
+    ListRegistrationsByRegistrant {
+        address: String,
+        /// (chain_id, code_id) to start after.
+        start_after: Option<(String, u64)>,
+        limit: Option<u32>,
+    },
+    /// Returns the nonce the address must sign its next registration with.
+    GetNonce {
+        address: String,
+    },
+    /// Returns the hooks of the package, or global hooks if no name and
+    /// chain ID are given.
+    ListHooks {
+        name: Option<String>,
+        chain_id: Option<String>,
+    },
+    /// Returns registrations depending on the given package, optionally
+    /// only those whose requirement matches the given version. Ordered by
+    /// the dependent's code ID.
+    ReverseDependencies {
+        name: String,
+        chain_id: String,
+        version: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the shortest sequence of declared migrations from the given
+    /// code ID to to_version, or the latest version if not provided.
+    MigrationPath {
+        chain_id: String,
+        from_code_id: u64,
+        to_version: Option<String>,
+    },
+    /// Identifies the package and version a contract on this chain is
+    /// running, reporting any mismatch with the contract's cw2 info.
+    IdentifyContract {
+        address: String,
+    },
+    /// Returns the catalogued chain with the given canonical ID or alias.
+    GetChain {
+        chain_id: String,
+    },
+    /// Returns the tombstone left when a version was unregistered in
+    /// immutable mode.
+    GetTombstone {
+        name: String,
+        chain_id: String,
+        version: String,
+    },
+    ListChains {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the registry's state in a stable order for moving to a new
+    /// registry with `Import`. Pass the key of the last
+    /// entry as start_after to get the next page.
+    Export {
+        start_after: Option<ExportKey>,
+        limit: Option<u32>,
+    },
+    /// Returns the open IBC channels changes are synced over.
+    ListIbcChannels {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub struct GetRegistrationResponse {
+    /// The canonical name the registration is stored under.
+    pub name: String,
+    /// The requested name if it was an alias redirected to the canonical
+    /// name.
+    pub redirected_from: Option<String>,
     pub registration: Registration,
+    /// The upstream registry that answered, if the name is not registered in
+    /// this registry.
+    pub source: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ResolveRequest {
+    pub name: String,
+    pub chain_id: String,
+    pub version: Option<String>,
+    pub include_prerelease: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ResolveResult {
+    /// Set if the registration was found.
+    pub registration: Option<GetRegistrationResponse>,
+    /// Set if the registration could not be resolved.
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ResolveManyResponse {
+    pub results: Vec<ResolveResult>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -86,6 +445,9 @@ pub struct InfoForCodeIdResponse {
     pub name: String,
     pub version: String,
     pub checksum: String,
+    /// The upstream registry that answered, if the code ID is not registered
+    /// in this registry.
+    pub source: Option<Addr>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -93,3 +455,418 @@ pub struct InfoForCodeIdResponse {
 pub struct ListRegistrationsResponse {
     pub registrations: Vec<Registration>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ListTagsResponse {
+    /// Pairs of (tag, version).
+    pub tags: Vec<(String, String)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct GetFeeResponse {
+    /// Any one of these payments may be used. If empty, registering is free.
+    pub payment_info: Vec<PaymentInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct PauseStatusResponse {
+    pub paused: bool,
+    /// When the current pause expires, if paused.
+    pub until: Option<Expiration>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct RegistrantEntry {
+    pub name: String,
+    pub chain_id: String,
+    pub registration: Registration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ListRegistrationsByRegistrantResponse {
+    pub registrations: Vec<RegistrantEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct InstantiatePackageResponse {
+    pub contract_address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct GetNonceResponse {
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ListHooksResponse {
+    pub hooks: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ReverseDependency {
+    pub name: String,
+    pub registration: Registration,
+    /// The dependent's requirement on the queried package.
+    pub version_req: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ReverseDependenciesResponse {
+    pub dependents: Vec<ReverseDependency>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MigrationHop {
+    pub from_code_id: u64,
+    pub to_code_id: u64,
+    pub to_version: String,
+    pub migration: Migration,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct MigrationPathResponse {
+    /// Migrations to perform in order. Empty if already at the target.
+    pub hops: Vec<MigrationHop>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct IdentifyContractResponse {
+    pub code_id: u64,
+    pub name: String,
+    pub registration: Registration,
+    /// The contract's cw2 info, if it stores any.
+    pub contract_version: Option<ContractVersion>,
+    /// Whether the cw2 contract name differs from the registered name.
+    pub name_mismatch: bool,
+    /// Whether the cw2 version differs from the registered version.
+    pub version_mismatch: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct GetChainResponse {
+    pub chain: Chain,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ListChainsResponse {
+    pub chains: Vec<Chain>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct IbcChannelEntry {
+    pub channel_id: String,
+    pub connection_id: String,
+    /// The chain_id whose changes are accepted over the channel, if its
+    /// connection is still trusted.
+    pub chain_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ListIbcChannelsResponse {
+    pub channels: Vec<IbcChannelEntry>,
+}
+
+/// A piece of registry state, exported in the order it must be imported
+/// in: chains and aliases before the registrations that refer to them, and
+/// config last since it may hand the registry to another admin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ExportEntry {
+    Chain {
+        chain: Chain,
+    },
+    Alias {
+        alias: String,
+        chain_id: String,
+        name: String,
+    },
+    Registration {
+        name: String,
+        chain_id: String,
+        registration: Registration,
+    },
+    Tombstone {
+        name: String,
+        chain_id: String,
+        tombstone: Tombstone,
+    },
+    Owner {
+        name: String,
+        chain_id: String,
+        owner: Addr,
+    },
+    Tag {
+        name: String,
+        chain_id: String,
+        tag: String,
+        version: String,
+    },
+    Migration {
+        chain_id: String,
+        from_code_id: u64,
+        to_code_id: u64,
+        migration: Migration,
+    },
+    /// The funds held for the deposit are not moved by importing it, and
+    /// must be sent to the new registry separately.
+    Deposit {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+        deposit: Deposit,
+    },
+    /// Like deposits, the escrowed fee must be sent to the new registry
+    /// separately.
+    GracePeriod {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+        grace_period: GracePeriod,
+    },
+    FeeExemption {
+        address: Addr,
+    },
+    NameFee {
+        name: String,
+        payment_info: Vec<PaymentInfo>,
+    },
+    ChainIdFee {
+        chain_id: String,
+        payment_info: Vec<PaymentInfo>,
+    },
+    GlobalHook {
+        hook: Addr,
+    },
+    Hook {
+        name: String,
+        chain_id: String,
+        hook: Addr,
+    },
+    IbcConnection {
+        connection_id: String,
+        chain_id: String,
+    },
+    Config {
+        config: Config,
+    },
+}
+
+impl ExportEntry {
+    /// The key to pass as start_after to export the entries after this one.
+    pub fn key(&self) -> ExportKey {
+        match self.clone() {
+            ExportEntry::Chain { chain } => ExportKey::Chain {
+                chain_id: chain.chain_id,
+            },
+            ExportEntry::Alias {
+                alias, chain_id, ..
+            } => ExportKey::Alias { alias, chain_id },
+            ExportEntry::Registration {
+                name,
+                chain_id,
+                registration,
+            } => ExportKey::Registration {
+                name,
+                chain_id,
+                code_id: registration.code_id,
+            },
+            ExportEntry::Tombstone {
+                name,
+                chain_id,
+                tombstone,
+            } => ExportKey::Tombstone {
+                name,
+                chain_id,
+                version: tombstone.version,
+            },
+            ExportEntry::Owner { name, chain_id, .. } => ExportKey::Owner { name, chain_id },
+            ExportEntry::Tag {
+                name,
+                chain_id,
+                tag,
+                ..
+            } => ExportKey::Tag {
+                name,
+                chain_id,
+                tag,
+            },
+            ExportEntry::Migration {
+                chain_id,
+                from_code_id,
+                to_code_id,
+                ..
+            } => ExportKey::Migration {
+                chain_id,
+                from_code_id,
+                to_code_id,
+            },
+            ExportEntry::Deposit {
+                name,
+                chain_id,
+                code_id,
+                ..
+            } => ExportKey::Deposit {
+                name,
+                chain_id,
+                code_id,
+            },
+            ExportEntry::GracePeriod {
+                name,
+                chain_id,
+                code_id,
+                ..
+            } => ExportKey::GracePeriod {
+                name,
+                chain_id,
+                code_id,
+            },
+            ExportEntry::FeeExemption { address } => ExportKey::FeeExemption { address },
+            ExportEntry::NameFee { name, .. } => ExportKey::NameFee { name },
+            ExportEntry::ChainIdFee { chain_id, .. } => ExportKey::ChainIdFee { chain_id },
+            ExportEntry::GlobalHook { hook } => ExportKey::GlobalHook { hook },
+            ExportEntry::Hook {
+                name,
+                chain_id,
+                hook,
+            } => ExportKey::Hook {
+                name,
+                chain_id,
+                hook,
+            },
+            ExportEntry::IbcConnection { connection_id, .. } => {
+                ExportKey::IbcConnection { connection_id }
+            }
+            ExportEntry::Config { .. } => ExportKey::Config {},
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ExportKey {
+    Chain {
+        chain_id: String,
+    },
+    Alias {
+        alias: String,
+        chain_id: String,
+    },
+    Registration {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+    },
+    Tombstone {
+        name: String,
+        chain_id: String,
+        version: String,
+    },
+    Owner {
+        name: String,
+        chain_id: String,
+    },
+    Tag {
+        name: String,
+        chain_id: String,
+        tag: String,
+    },
+    Migration {
+        chain_id: String,
+        from_code_id: u64,
+        to_code_id: u64,
+    },
+    Deposit {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+    },
+    GracePeriod {
+        name: String,
+        chain_id: String,
+        code_id: u64,
+    },
+    FeeExemption {
+        address: Addr,
+    },
+    NameFee {
+        name: String,
+    },
+    ChainIdFee {
+        chain_id: String,
+    },
+    GlobalHook {
+        hook: Addr,
+    },
+    Hook {
+        name: String,
+        chain_id: String,
+        hook: Addr,
+    },
+    IbcConnection {
+        connection_id: String,
+    },
+    Config {},
+}
+
+/// Some state is left out on purpose. Signer nonces start over, since
+/// signatures are only valid for the registry they name. IBC channels are
+/// bound to this registry's port and must be opened again. Pause and import
+/// seal state, and rate limit windows, only concern this registry. Indexes
+/// are rebuilt from the registrations as they are imported.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct ExportResponse {
+    pub entries: Vec<ExportEntry>,
+}
+
+/// Messages sent to hook contracts.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeIdRegistryHookMsg {
+    NewRegistration {
+        name: String,
+        chain_id: String,
+        registration: Registration,
+    },
+}
+
+impl CodeIdRegistryHookMsg {
+    /// Serializes the message wrapped in `CodeIdRegistryHookExecuteMsg`.
+    pub fn into_binary(self) -> StdResult<Binary> {
+        to_binary(&CodeIdRegistryHookExecuteMsg::CodeIdRegistryHook(self))
+    }
+
+    /// Creates a message executing the hook on the given contract.
+    pub fn into_cosmos_msg<T: Into<String>>(self, contract_addr: T) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: self.into_binary()?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The execute message hook contracts must accept.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CodeIdRegistryHookExecuteMsg {
+    CodeIdRegistryHook(CodeIdRegistryHookMsg),
+}
@@ -0,0 +1,257 @@
+//! A mock registry for testing contracts that use the registry with
+//! cw-multi-test. Enable the `multitest` feature to use it outside this
+//! crate.
+
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+
+use crate::helpers::CodeIdRegistryContract;
+use crate::msg::{ExecuteMsg, InstantiateMsg, ReceiveMsg};
+use crate::state::PaymentInfo;
+
+pub fn registry_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    )
+    .with_reply(crate::contract::reply);
+    Box::new(contract)
+}
+
+pub fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn native_payment(denom: impl Into<String>, amount: u128) -> PaymentInfo {
+    PaymentInfo::NativePayment {
+        token_denom: denom.into(),
+        payment_amount: Uint128::new(amount),
+    }
+}
+
+pub fn cw20_payment(token: &Addr, amount: u128) -> PaymentInfo {
+    PaymentInfo::Cw20Payment {
+        token_address: token.to_string(),
+        payment_amount: Uint128::new(amount),
+    }
+}
+
+/// Instantiate a CW20 token to pay fees with, giving each address its
+/// balance.
+pub fn mock_cw20(app: &mut App, minter: &Addr, balances: &[(&str, u128)]) -> AnyResult<Addr> {
+    let code_id = app.store_code(cw20_contract());
+    app.instantiate_contract(
+        code_id,
+        minter.clone(),
+        &cw20_base::msg::InstantiateMsg {
+            name: "Registry Fee Token".to_string(),
+            symbol: "FEE".to_string(),
+            decimals: 6,
+            initial_balances: balances
+                .iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(*amount),
+                })
+                .collect(),
+            mint: None,
+            marketing: None,
+        },
+        &[],
+        "registry fee token",
+        None,
+    )
+}
+
+/// Give `addr` native tokens to pay fees with.
+pub fn fund_native(app: &mut App, addr: &Addr, funds: Vec<Coin>) -> AnyResult<()> {
+    app.init_modules(|router, _, storage| router.bank.init_balance(storage, addr, funds))
+}
+
+/// A registration to seed the mock registry with or to register with
+/// `register_paid`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockRegistration {
+    pub name: String,
+    pub chain_id: String,
+    pub version: String,
+    pub code_id: u64,
+    pub checksum: String,
+}
+
+impl MockRegistration {
+    /// The checksum defaults to one derived from the code ID.
+    pub fn new(
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        version: impl Into<String>,
+        code_id: u64,
+    ) -> Self {
+        MockRegistration {
+            name: name.into(),
+            chain_id: chain_id.into(),
+            version: version.into(),
+            code_id,
+            checksum: format!("{:064x}", code_id),
+        }
+    }
+}
+
+/// Register `registration` as `sender`, paying the fee described by `payment`
+/// in whichever way it requires.
+pub fn register_paid(
+    app: &mut App,
+    registry: &CodeIdRegistryContract,
+    sender: &Addr,
+    payment: &PaymentInfo,
+    registration: MockRegistration,
+) -> AnyResult<AppResponse> {
+    let MockRegistration {
+        name,
+        chain_id,
+        version,
+        code_id,
+        checksum,
+    } = registration;
+    let msg = match payment {
+        PaymentInfo::NativePayment {
+            token_denom,
+            payment_amount,
+        } => registry.register(
+            name,
+            version,
+            chain_id,
+            code_id,
+            checksum,
+            coins(payment_amount.u128(), token_denom),
+        )?,
+        PaymentInfo::Cw20Payment {
+            token_address,
+            payment_amount,
+        } => registry.call_cw20(
+            Addr::unchecked(token_address),
+            *payment_amount,
+            ReceiveMsg::Register {
+                name,
+                version,
+                chain_id,
+                code_id,
+                checksum,
+                dependencies: None,
+            },
+        )?,
+    };
+    app.execute(sender.clone(), msg)
+}
+
+/// Builds a registry pre-populated with registrations and owners across any
+/// number of chains. Seeded registrations are registered by the admin before
+/// fees are configured, so they are free.
+#[derive(Clone, Debug)]
+pub struct MockRegistryBuilder {
+    admin: Addr,
+    payment_info: Vec<PaymentInfo>,
+    fee_recipients: Option<Vec<(String, Decimal)>>,
+    registrations: Vec<MockRegistration>,
+    owners: Vec<(String, String, String)>,
+}
+
+impl MockRegistryBuilder {
+    pub fn new(admin: impl Into<String>) -> Self {
+        MockRegistryBuilder {
+            admin: Addr::unchecked(admin),
+            payment_info: vec![],
+            fee_recipients: None,
+            registrations: vec![],
+            owners: vec![],
+        }
+    }
+
+    /// Accept `payment` as a registration fee. With none, registering is
+    /// free.
+    pub fn with_payment(mut self, payment: PaymentInfo) -> Self {
+        self.payment_info.push(payment);
+        self
+    }
+
+    pub fn with_fee_recipients(mut self, fee_recipients: Vec<(String, Decimal)>) -> Self {
+        self.fee_recipients = Some(fee_recipients);
+        self
+    }
+
+    pub fn with_registration(mut self, registration: MockRegistration) -> Self {
+        self.registrations.push(registration);
+        self
+    }
+
+    pub fn with_owner(
+        mut self,
+        name: impl Into<String>,
+        chain_id: impl Into<String>,
+        owner: impl Into<String>,
+    ) -> Self {
+        self.owners
+            .push((name.into(), chain_id.into(), owner.into()));
+        self
+    }
+
+    pub fn build(self, app: &mut App) -> AnyResult<CodeIdRegistryContract> {
+        let code_id = app.store_code(registry_contract());
+        let addr = app.instantiate_contract(
+            code_id,
+            self.admin.clone(),
+            &InstantiateMsg {
+                admin: self.admin.to_string(),
+                payment_info: vec![],
+                fee_recipients: self.fee_recipients,
+            },
+            &[],
+            "Code ID Registry",
+            None,
+        )?;
+        let registry = CodeIdRegistryContract(addr);
+
+        for registration in self.registrations {
+            app.execute(
+                self.admin.clone(),
+                registry.register(
+                    registration.name,
+                    registration.version,
+                    registration.chain_id,
+                    registration.code_id,
+                    registration.checksum,
+                    vec![],
+                )?,
+            )?;
+        }
+        for (name, chain_id, owner) in self.owners {
+            app.execute(
+                self.admin.clone(),
+                registry.set_owner(name, chain_id, Some(owner))?,
+            )?;
+        }
+        if !self.payment_info.is_empty() {
+            app.execute_contract(
+                self.admin.clone(),
+                registry.addr(),
+                &ExecuteMsg::UpdateConfig {
+                    admin: None,
+                    payment_info: Some(self.payment_info),
+                    fee_recipients: None,
+                    validate_chain_ids: None,
+                },
+                &[],
+            )?;
+        }
+
+        Ok(registry)
+    }
+}
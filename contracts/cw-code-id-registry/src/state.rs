@@ -1,12 +1,12 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum PaymentInfo {
-    None {},
     NativePayment {
         token_denom: String,
         payment_amount: Uint128,
@@ -17,13 +17,83 @@ pub enum PaymentInfo {
     },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub struct Config {
     /// Admin receives fees, can register anything, and set owners to allow
     /// future registration.
     pub admin: Addr,
-    pub payment_info: PaymentInfo,
+    /// Payments accepted for registering. Any one of them may be used. If
+    /// empty, registering is free.
+    pub payment_info: Vec<PaymentInfo>,
+    /// Addresses that fees are split between, with the share each one
+    /// receives. Shares sum to one. If empty, the admin receives all fees.
+    /// Any rounding dust left after splitting goes to the first recipient.
+    pub fee_recipients: Vec<(Addr, Decimal)>,
+    /// Whether registrations must use the canonical chain ID of a chain in
+    /// the catalogue.
+    #[serde(default)]
+    pub validate_chain_ids: bool,
+    /// Registries queried in order for names and code IDs not registered in
+    /// this one.
+    #[serde(default)]
+    pub upstreams: Vec<Addr>,
+    /// How long after registering the owner may unregister. If None, only
+    /// the admin may unregister.
+    #[serde(default)]
+    pub unregister_window: Option<Duration>,
+    /// Whether fees are held during the unregister window and refunded if the
+    /// owner unregisters within it.
+    #[serde(default)]
+    pub refund_on_unregister: bool,
+    /// Whether unregistering leaves a tombstone so the version and code ID
+    /// can never be registered again.
+    #[serde(default)]
+    pub immutable: bool,
+    /// Limits how often each registrant other than the admin may register.
+    #[serde(default)]
+    pub rate_limit: Option<RateLimit>,
+    /// Held for each registration by someone other than the admin until it
+    /// is yanked or unregistered.
+    #[serde(default)]
+    pub deposit: Option<PaymentInfo>,
+    /// Gas each hook may use when notified. A hook that runs out fails like
+    /// any other broken hook, without blocking the registration.
+    #[serde(default = "default_hook_gas_limit")]
+    pub hook_gas_limit: u64,
+}
+
+pub const DEFAULT_HOOK_GAS_LIMIT: u64 = 500_000;
+
+fn default_hook_gas_limit() -> u64 {
+    DEFAULT_HOOK_GAS_LIMIT
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct RateLimit {
+    pub max_registrations: u32,
+    pub window: Duration,
+}
+
+/// Registrations made by a registrant in the current rate limit window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct RateLimitWindow {
+    pub expires: Expiration,
+    pub count: u32,
+}
+
+/// A deposit held for a registration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Deposit {
+    pub depositor: Addr,
+    pub amount: PaymentInfo,
+    /// Whether the deposit was returned when the version was yanked. Such
+    /// versions can't be unyanked.
+    #[serde(default)]
+    pub returned: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -33,9 +103,81 @@ pub struct Registration {
     pub version: String,
     pub code_id: u64,
     pub checksum: String,
+    /// Whether the version is a pre-release, such as `v1.0.0-rc.1`.
+    /// Pre-releases are skipped when resolving the latest version unless
+    /// explicitly included.
+    pub prerelease: bool,
+    /// Packages this version depends on.
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+    /// Whether the version has been yanked. Yanked versions are skipped when
+    /// resolving the latest version but can still be requested explicitly.
+    #[serde(default)]
+    pub yanked: bool,
+}
+
+/// Left in place of a version unregistered in immutable mode.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Tombstone {
+    pub version: String,
+    pub code_id: u64,
+    pub removed_by: Addr,
+    pub reason: Option<String>,
+}
+
+/// The window in which the owner may unregister a registration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct GracePeriod {
+    pub expires: Expiration,
+    /// Who paid the fee.
+    pub payer: Addr,
+    /// The fee held until the window expires, if it may be refunded.
+    pub escrowed_fee: Option<PaymentInfo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Dependency {
+    pub name: String,
+    /// Cargo style version requirement, such as `^1.2` or `>=0.1, <0.3`.
+    pub version_req: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkType {
+    Mainnet,
+    Testnet,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Chain {
+    /// Canonical chain ID used in registrations.
+    pub chain_id: String,
+    /// Human readable name.
+    pub name: String,
+    pub bech32_prefix: String,
+    pub network_type: NetworkType,
+    /// Other IDs the chain is known by, such as `testnets/junotestnet`.
+    pub aliases: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub struct Migration {
+    /// Hash of the JSON schema of the `MigrateMsg` to use.
+    pub migrate_msg_schema_hash: Option<String>,
+    pub notes: Option<String>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+/// When set, mutations are blocked until this expires.
+pub const PAUSED_UNTIL: Item<Expiration> = Item::new("paused_until");
+/// Set once the admin seals imports, after which `Import` is rejected.
+pub const IMPORT_SEALED: Item<bool> = Item::new("import_sealed");
 
 /// Map (name, chain_id, version) to a code_id.
 pub const NAME_CHAIN_ID_VERSION_TO_CODE_ID: Map<(String, String, String), u64> =
@@ -48,3 +190,74 @@ pub const CHAIN_ID_CODE_ID_TO_NAME: Map<(String, u64), String> =
 /// Map (name, chain_id, code_id) to the registration.
 pub const NAME_CHAIN_ID_CODE_ID_TO_REGISTRATION: Map<(String, String, u64), Registration> =
     Map::new("name_chain_id_code_id_to_registration");
+/// Map (alias, chain_id) to the name lookups of the alias redirect to.
+pub const NAME_CHAIN_ID_TO_ALIAS_TARGET: Map<(String, String), String> =
+    Map::new("name_chain_id_to_alias_target");
+/// Map (name, chain_id, tag) to the tagged version.
+pub const NAME_CHAIN_ID_TAG_TO_VERSION: Map<(String, String, String), String> =
+    Map::new("name_chain_id_tag_to_version");
+
+/// Addresses that can register without paying fees.
+pub const FEE_EXEMPT_ADDRESSES: Map<Addr, Empty> = Map::new("fee_exempt_addresses");
+/// Map name to the payments accepted for registering it, overriding the
+/// chain ID and configured payments.
+pub const NAME_TO_PAYMENT_INFO: Map<String, Vec<PaymentInfo>> = Map::new("name_to_payment_info");
+/// Map chain_id to the payments accepted for registering on it, overriding
+/// the configured payments.
+pub const CHAIN_ID_TO_PAYMENT_INFO: Map<String, Vec<PaymentInfo>> =
+    Map::new("chain_id_to_payment_info");
+
+/// Map (registered_by, chain_id, code_id) to the name it was registered under.
+pub const REGISTRANT_CHAIN_ID_CODE_ID_TO_NAME: Map<(Addr, String, u64), String> =
+    Map::new("registrant_chain_id_code_id_to_name");
+
+/// Map signer to the next nonce it must use for a signed registration.
+pub const SIGNER_NONCE: Map<Addr, u64> = Map::new("signer_nonce");
+
+/// Hook contracts notified of every new registration.
+pub const GLOBAL_HOOKS: Map<Addr, Empty> = Map::new("global_hooks");
+/// Map (name, chain_id, hook) to nothing, for hook contracts notified of new
+/// registrations of a package.
+pub const NAME_CHAIN_ID_HOOK: Map<(String, String, Addr), Empty> = Map::new("name_chain_id_hook");
+
+/// Map (dependency name, chain_id, dependent code_id) to the dependent's
+/// name.
+pub const DEPENDENCY_CHAIN_ID_CODE_ID_TO_NAME: Map<(String, String, u64), String> =
+    Map::new("dependency_chain_id_code_id_to_name");
+
+/// Map (chain_id, from_code_id, to_code_id) to a declared migration.
+pub const CHAIN_ID_FROM_CODE_ID_TO_CODE_ID_TO_MIGRATION: Map<(String, u64, u64), Migration> =
+    Map::new("chain_id_from_code_id_to_code_id_to_migration");
+
+/// Map canonical chain_id to the catalogued chain.
+pub const CHAINS: Map<String, Chain> = Map::new("chains");
+/// Map chain alias to the canonical chain_id.
+pub const CHAIN_ALIAS_TO_CHAIN_ID: Map<String, String> = Map::new("chain_alias_to_chain_id");
+
+/// Map IBC connection_id to the chain_id of the chain it connects to, which
+/// is the only chain_id whose changes are accepted over it.
+pub const IBC_CONNECTION_ID_TO_CHAIN_ID: Map<String, String> =
+    Map::new("ibc_connection_id_to_chain_id");
+/// Map open IBC channel_id to the connection_id it uses.
+pub const IBC_CHANNEL_ID_TO_CONNECTION_ID: Map<String, String> =
+    Map::new("ibc_channel_id_to_connection_id");
+
+/// Map (name, chain_id, code_id) to the window in which the owner may
+/// unregister it, until the window expires and any held fee is released.
+pub const NAME_CHAIN_ID_CODE_ID_TO_GRACE_PERIOD: Map<(String, String, u64), GracePeriod> =
+    Map::new("name_chain_id_code_id_to_grace_period");
+
+/// Map (name, chain_id, version) to the tombstone of a removed version.
+pub const NAME_CHAIN_ID_VERSION_TO_TOMBSTONE: Map<(String, String, String), Tombstone> =
+    Map::new("name_chain_id_version_to_tombstone");
+/// Map (chain_id, code_id) to the name a removed code ID was registered
+/// under.
+pub const CHAIN_ID_CODE_ID_TO_TOMBSTONE_NAME: Map<(String, u64), String> =
+    Map::new("chain_id_code_id_to_tombstone_name");
+
+/// Map registrant to their current rate limit window.
+pub const REGISTRANT_TO_RATE_LIMIT_WINDOW: Map<Addr, RateLimitWindow> =
+    Map::new("registrant_to_rate_limit_window");
+/// Map (name, chain_id, code_id) to the deposit held for it.
+pub const NAME_CHAIN_ID_CODE_ID_TO_DEPOSIT: Map<(String, String, u64), Deposit> =
+    Map::new("name_chain_id_code_id_to_deposit");
@@ -1,38 +1,53 @@
+use crate::contract::{execute, query};
+use crate::helpers::CodeIdRegistryContract;
+use crate::ibc::{
+    ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive,
+    ibc_packet_timeout, RegistryAck, RegistryPacket, IBC_ORDERING, IBC_VERSION,
+};
 use crate::msg::{
-    ExecuteMsg, GetRegistrationResponse, InfoForCodeIdResponse, InstantiateMsg,
-    ListRegistrationsResponse, QueryMsg, ReceiveMsg,
+    CodeIdRegistryHookExecuteMsg, CodeIdRegistryHookMsg, ExecuteMsg, ExportEntry, ExportResponse,
+    GetChainResponse, GetFeeResponse, GetNonceResponse, GetRegistrationResponse, IbcChannelEntry,
+    IdentifyContractResponse, InfoForCodeIdResponse, InstantiateMsg, InstantiatePackageMsg,
+    InstantiatePackageResponse, ListChainsResponse, ListHooksResponse, ListIbcChannelsResponse,
+    ListRegistrationsByRegistrantResponse, ListRegistrationsResponse, ListTagsResponse,
+    MigrationPathResponse, PauseStatusResponse, QueryMsg, ReceiveMsg, RegisterSignedPayload,
+    ResolveManyResponse, ResolveRequest, ReverseDependenciesResponse, SignedRegistration,
+    MAX_RESOLVE_BATCH_SIZE,
+};
+use crate::multitest::{
+    cw20_contract, cw20_payment, fund_native, mock_cw20, native_payment, register_paid,
+    registry_contract, MockRegistration, MockRegistryBuilder,
+};
+use crate::state::{
+    Chain, Config, Dependency, Deposit, NetworkType, PaymentInfo, RateLimit, Registration,
+    Tombstone, DEFAULT_HOOK_GAS_LIMIT,
 };
-use crate::state::{Config, PaymentInfo, Registration};
 use crate::ContractError;
 use anyhow::Result as AnyResult;
-use cosmwasm_std::{coin, coins, to_binary, Addr, Coin, Empty, StdResult, Uint128};
-use cw20::{BalanceResponse, Cw20Coin};
-use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
-use cw_utils::PaymentError;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_ibc_channel_close_confirm, mock_ibc_channel_connect_ack,
+    mock_ibc_channel_open_try, mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout,
+    mock_info, MockApi,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, from_slice, to_binary, to_vec, Addr, Api, Attribute, Binary,
+    CanonicalAddr, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, IbcAcknowledgement, IbcMsg,
+    IbcOrder, MessageInfo, RecoverPubkeyError, Response, StdError, StdResult, Uint128,
+    VerificationError,
+};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ReceiveMsg};
+use cw_multi_test::{App, AppBuilder, AppResponse, Contract, ContractWrapper, Executor};
+use cw_utils::{Duration, Expiration, PaymentError};
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
 
 const USER_ADDR: &str = "user";
 const OTHER_USER_ADDR: &str = "other_user";
 const ADMIN_ADDR: &str = "admin";
 const CHAIN_ID: &str = "chain-id";
 
-fn cw20_contract() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new(
-        cw20_base::contract::execute,
-        cw20_base::contract::instantiate,
-        cw20_base::contract::query,
-    );
-    Box::new(contract)
-}
-
-fn registry_contract() -> Box<dyn Contract<Empty>> {
-    let contract = ContractWrapper::new(
-        crate::contract::execute,
-        crate::contract::instantiate,
-        crate::contract::query,
-    );
-    Box::new(contract)
-}
-
 fn setup_app() -> App {
     let amount = Uint128::new(10000);
     App::new(|r, _a, s| {
@@ -120,7 +135,7 @@ fn create_token(app: &mut App) -> Addr {
     .unwrap()
 }
 
-fn setup_test_case(app: &mut App, payment_info: PaymentInfo) -> Addr {
+fn setup_test_case(app: &mut App, payment_info: Vec<PaymentInfo>) -> Addr {
     let code_id = app.store_code(registry_contract());
     app.instantiate_contract(
         code_id,
@@ -128,6 +143,7 @@ fn setup_test_case(app: &mut App, payment_info: PaymentInfo) -> Addr {
         &InstantiateMsg {
             admin: ADMIN_ADDR.to_string(),
             payment_info,
+            fee_recipients: None,
         },
         &[],
         "Code ID Registry",
@@ -147,10 +163,11 @@ fn test_instantiate() {
         Addr::unchecked(ADMIN_ADDR),
         &InstantiateMsg {
             admin: ADMIN_ADDR.to_string(),
-            payment_info: PaymentInfo::Cw20Payment {
+            payment_info: vec![PaymentInfo::Cw20Payment {
                 token_address: token_addr.to_string(),
                 payment_amount: Uint128::new(50),
-            },
+            }],
+            fee_recipients: None,
         },
         &[],
         "Code ID Registry",
@@ -178,6 +195,7 @@ fn register_cw20(
             chain_id: CHAIN_ID.to_string(),
             code_id,
             checksum: version,
+            dependencies: None,
         })
         .unwrap(),
     };
@@ -199,6 +217,7 @@ fn register_native(
         chain_id: CHAIN_ID.to_string(),
         code_id,
         checksum: version,
+        dependencies: None,
     };
     app.execute_contract(sender, contract_addr, &msg, &funds)
 }
@@ -237,6 +256,7 @@ fn unregister(
         name,
         chain_id: CHAIN_ID.to_string(),
         code_id,
+        reason: None,
     };
     app.execute_contract(sender, contract_addr, &msg, &[])
 }
@@ -245,12 +265,15 @@ fn update_config(
     app: &mut App,
     contract_addr: Addr,
     admin: Option<String>,
-    payment_info: Option<PaymentInfo>,
+    payment_info: Option<Vec<PaymentInfo>>,
+    fee_recipients: Option<Vec<(String, Decimal)>>,
     sender: Addr,
 ) -> AnyResult<AppResponse> {
     let msg = ExecuteMsg::UpdateConfig {
         admin,
         payment_info,
+        fee_recipients,
+        validate_chain_ids: None,
     };
     app.execute_contract(sender, contract_addr, &msg, &[])
 }
@@ -265,6 +288,7 @@ fn query_get_registration(
         name,
         chain_id: CHAIN_ID.to_string(),
         version,
+        include_prerelease: None,
     };
     app.wrap().query_wasm_smart(contract_addr, &msg)
 }
@@ -289,10 +313,69 @@ fn query_list_registrations(
     let msg = QueryMsg::ListRegistrations {
         name,
         chain_id: CHAIN_ID.to_string(),
+        include_prerelease: None,
+    };
+    app.wrap().query_wasm_smart(contract_addr, &msg)
+}
+
+fn query_get_fee(
+    app: &mut App,
+    contract_addr: Addr,
+    name: String,
+    sender: String,
+) -> StdResult<GetFeeResponse> {
+    let msg = QueryMsg::GetFee {
+        name,
+        chain_id: CHAIN_ID.to_string(),
+        sender,
+    };
+    app.wrap().query_wasm_smart(contract_addr, &msg)
+}
+
+fn query_pause_status(app: &mut App, contract_addr: Addr) -> PauseStatusResponse {
+    let msg = QueryMsg::PauseStatus {};
+    app.wrap().query_wasm_smart(contract_addr, &msg).unwrap()
+}
+
+fn set_tag(
+    app: &mut App,
+    contract_addr: Addr,
+    name: String,
+    tag: String,
+    version: String,
+    sender: Addr,
+) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::SetTag {
+        name,
+        chain_id: CHAIN_ID.to_string(),
+        tag,
+        version,
+    };
+    app.execute_contract(sender, contract_addr, &msg, &[])
+}
+
+fn query_get_tagged_registration(
+    app: &mut App,
+    contract_addr: Addr,
+    name: String,
+    tag: String,
+) -> StdResult<GetRegistrationResponse> {
+    let msg = QueryMsg::GetTaggedRegistration {
+        name,
+        chain_id: CHAIN_ID.to_string(),
+        tag,
     };
     app.wrap().query_wasm_smart(contract_addr, &msg)
 }
 
+fn query_list_tags(app: &mut App, contract_addr: Addr, name: String) -> ListTagsResponse {
+    let msg = QueryMsg::ListTags {
+        name,
+        chain_id: CHAIN_ID.to_string(),
+    };
+    app.wrap().query_wasm_smart(contract_addr, &msg).unwrap()
+}
+
 fn query_config(app: &mut App, contract_addr: Addr) -> Config {
     let msg = QueryMsg::Config {};
     app.wrap().query_wasm_smart(contract_addr, &msg).unwrap()
@@ -304,10 +387,10 @@ fn test_register_cw20() {
     let token = create_token(&mut app);
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::Cw20Payment {
+        vec![PaymentInfo::Cw20Payment {
             token_address: token.to_string(),
             payment_amount: Uint128::new(50),
-        },
+        }],
     );
     let other_token = create_token(&mut app); // To be used when sending wrong token
     let name: &str = "Name";
@@ -337,7 +420,7 @@ fn test_register_cw20() {
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(err, ContractError::InvalidPayment {});
+    assert_eq!(err, ContractError::UnrecognizedNativeToken {});
 
     // Registering using wrong cw20 should fail.
     let err: ContractError = register_cw20(
@@ -369,21 +452,6 @@ fn test_register_cw20() {
     .unwrap();
     assert_eq!(err, ContractError::IncorrectPaymentAmount {});
 
-    // Sending too much should fail.
-    let err: ContractError = register_cw20(
-        &mut app,
-        contract.clone(),
-        coin(75, token.to_string()),
-        name.to_string(),
-        version.to_string(),
-        code_id,
-        Addr::unchecked(USER_ADDR),
-    )
-    .unwrap_err()
-    .downcast()
-    .unwrap();
-    assert_eq!(err, ContractError::IncorrectPaymentAmount {});
-
     // Sending correct amounts should succeed.
     register_cw20(
         &mut app,
@@ -418,6 +486,9 @@ fn test_register_cw20() {
             version: version.to_string(),
             code_id,
             checksum: version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
         }
     );
     assert_eq!(
@@ -451,10 +522,10 @@ fn test_register_native() {
     let pay_denom = "ujuno";
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::NativePayment {
+        vec![PaymentInfo::NativePayment {
             token_denom: pay_denom.to_string(),
             payment_amount: Uint128::new(50),
-        },
+        }],
     );
     let name: &str = "Name";
     let version: &str = "0.0.1";
@@ -483,7 +554,7 @@ fn test_register_native() {
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(err, ContractError::InvalidPayment {});
+    assert_eq!(err, ContractError::UnrecognizedCw20 {});
 
     // Registering using wrong denom should fail.
     let err: ContractError = register_native(
@@ -498,10 +569,7 @@ fn test_register_native() {
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(
-        err,
-        ContractError::Payment(PaymentError::MissingDenom("ujuno".to_string()))
-    );
+    assert_eq!(err, ContractError::UnrecognizedNativeToken {});
 
     // Sending too little should fail.
     let err: ContractError = register_native(
@@ -518,21 +586,6 @@ fn test_register_native() {
     .unwrap();
     assert_eq!(err, ContractError::IncorrectPaymentAmount {});
 
-    // Sending too much should fail.
-    let err: ContractError = register_native(
-        &mut app,
-        contract.clone(),
-        coins(75, pay_denom),
-        name.to_string(),
-        version.to_string(),
-        code_id,
-        Addr::unchecked(USER_ADDR),
-    )
-    .unwrap_err()
-    .downcast()
-    .unwrap();
-    assert_eq!(err, ContractError::IncorrectPaymentAmount {});
-
     // Sending correct amounts should succeed.
     register_native(
         &mut app,
@@ -570,6 +623,9 @@ fn test_register_native() {
             version: version.to_string(),
             code_id,
             checksum: version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
         }
     );
     assert_eq!(
@@ -597,19 +653,33 @@ fn test_register_native() {
 }
 
 #[test]
-fn test_immutability() {
+fn test_register_multiple_payments() {
     let mut app = setup_app();
-    let pay_denom = "ujuno";
+    let token = create_token(&mut app);
+    let other_token = create_token(&mut app);
+    let unaccepted_token = create_token(&mut app);
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::NativePayment {
-            token_denom: pay_denom.to_string(),
-            payment_amount: Uint128::new(50),
-        },
+        vec![
+            PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(50),
+            },
+            PaymentInfo::NativePayment {
+                token_denom: "uatom".to_string(),
+                payment_amount: Uint128::new(10),
+            },
+            PaymentInfo::Cw20Payment {
+                token_address: token.to_string(),
+                payment_amount: Uint128::new(100),
+            },
+            PaymentInfo::Cw20Payment {
+                token_address: other_token.to_string(),
+                payment_amount: Uint128::new(200),
+            },
+        ],
     );
     let name: &str = "Name";
-    let version: &str = "0.0.1";
-    let code_id: u64 = 1;
 
     // Give user address ownership over name.
     set_owner(
@@ -621,112 +691,160 @@ fn test_immutability() {
     )
     .unwrap();
 
-    // Register.
-    register_native(
+    // Sending no funds should fail.
+    let err: ContractError = register_native(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
+        vec![],
         name.to_string(),
-        version.to_string(),
-        code_id,
+        "0.0.1".to_string(),
+        1,
         Addr::unchecked(USER_ADDR),
     )
+    .unwrap_err()
+    .downcast()
     .unwrap();
+    assert_eq!(err, ContractError::Payment(PaymentError::NoFunds {}));
 
-    // Check registration with and without version.
-    let resp_without_version =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
-    let resp_with_version = query_get_registration(
+    // Sending less than the accepted denom's amount should fail.
+    let err: ContractError = register_native(
         &mut app,
         contract.clone(),
+        coins(5, "uatom"),
         name.to_string(),
-        Some(version.to_string()),
+        "0.0.1".to_string(),
+        1,
+        Addr::unchecked(USER_ADDR),
     )
+    .unwrap_err()
+    .downcast()
     .unwrap();
-    assert_eq!(
-        resp_without_version.registration,
-        Registration {
-            registered_by: Addr::unchecked(USER_ADDR),
-            version: version.to_string(),
-            code_id,
-            checksum: version.to_string(),
-        }
-    );
-    assert_eq!(
-        resp_without_version.registration,
-        resp_with_version.registration,
-    );
+    assert_eq!(err, ContractError::IncorrectPaymentAmount {});
 
-    // Should fail with Code ID already registered.
-    let err: ContractError = register_native(
+    // Registering using a CW20 that is not accepted should fail.
+    let err: ContractError = register_cw20(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
+        coin(100, unaccepted_token.to_string()),
         name.to_string(),
-        version.to_string(),
-        code_id,
+        "0.0.1".to_string(),
+        1,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(
-        err,
-        ContractError::CodeIDAlreadyRegistered(code_id, CHAIN_ID.to_string())
-    );
+    assert_eq!(err, ContractError::UnrecognizedCw20 {});
 
-    // Should fail with version already registered.
-    let err: ContractError = register_native(
+    // Every accepted payment should succeed.
+    register_native(
         &mut app,
-        contract,
-        coins(50, pay_denom),
+        contract.clone(),
+        coins(50, "ujuno"),
         name.to_string(),
-        version.to_string(),
-        code_id + 1,
+        "0.0.1".to_string(),
+        1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(10, "uatom"),
+        name.to_string(),
+        "0.0.2".to_string(),
+        2,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    register_cw20(
+        &mut app,
+        contract.clone(),
+        coin(100, token.to_string()),
+        name.to_string(),
+        "0.0.3".to_string(),
+        3,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    register_cw20(
+        &mut app,
+        contract.clone(),
+        coin(200, other_token.to_string()),
+        name.to_string(),
+        "0.0.4".to_string(),
+        4,
         Addr::unchecked(USER_ADDR),
     )
+    .unwrap();
+
+    // Check that admin received each payment.
+    let balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(ADMIN_ADDR), "ujuno")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(10050));
+    let balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(ADMIN_ADDR), "uatom")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(10010));
+    let balance = query_cw20_balance(&mut app, token, Addr::unchecked(ADMIN_ADDR));
+    assert_eq!(balance, Uint128::new(1100));
+    let balance = query_cw20_balance(&mut app, other_token, Addr::unchecked(ADMIN_ADDR));
+    assert_eq!(balance, Uint128::new(1200));
+
+    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(registrations.len(), 4);
+
+    // Configuring the same denom twice should fail.
+    let err: ContractError = update_config(
+        &mut app,
+        contract,
+        None,
+        Some(vec![
+            PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(50),
+            },
+            PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(25),
+            },
+        ]),
+        None,
+        Addr::unchecked(ADMIN_ADDR),
+    )
     .unwrap_err()
     .downcast()
     .unwrap();
     assert_eq!(
         err,
-        ContractError::VersionAlreadyRegistered(
-            version.to_string(),
-            name.to_string(),
-            CHAIN_ID.to_string()
-        )
+        ContractError::DuplicatePaymentToken("ujuno".to_string())
     );
 }
 
 #[test]
-fn test_set_owner() {
+fn test_refund_overpayment() {
     let mut app = setup_app();
+    let token = create_token(&mut app);
     let pay_denom = "ujuno";
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::NativePayment {
-            token_denom: pay_denom.to_string(),
-            payment_amount: Uint128::new(50),
-        },
+        vec![
+            PaymentInfo::NativePayment {
+                token_denom: pay_denom.to_string(),
+                payment_amount: Uint128::new(50),
+            },
+            PaymentInfo::Cw20Payment {
+                token_address: token.to_string(),
+                payment_amount: Uint128::new(50),
+            },
+        ],
     );
     let name: &str = "Name";
-    let version: &str = "0.0.1";
-    let code_id: u64 = 1;
-
-    // Register fails with unauthorized.
-    let err: ContractError = register_native(
-        &mut app,
-        contract.clone(),
-        coins(50, pay_denom),
-        name.to_string(),
-        version.to_string(),
-        code_id,
-        Addr::unchecked(USER_ADDR),
-    )
-    .unwrap_err()
-    .downcast()
-    .unwrap();
-    assert_eq!(err, ContractError::Unauthorized {});
 
     // Give user address ownership over name.
     set_owner(
@@ -738,204 +856,278 @@ fn test_set_owner() {
     )
     .unwrap();
 
-    // Register succeeds.
+    // Sending too much native should succeed and refund the difference.
     register_native(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
+        coins(75, pay_denom),
         name.to_string(),
-        version.to_string(),
-        code_id,
+        "0.0.1".to_string(),
+        1,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap();
 
-    // Check registration with and without version.
-    let resp_without_version =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
-    let resp_with_version = query_get_registration(
-        &mut app,
-        contract.clone(),
-        name.to_string(),
-        Some(version.to_string()),
-    )
-    .unwrap();
-    assert_eq!(
-        resp_without_version.registration,
-        Registration {
-            registered_by: Addr::unchecked(USER_ADDR),
-            version: version.to_string(),
-            code_id,
-            checksum: version.to_string(),
-        }
-    );
-    assert_eq!(
-        resp_without_version.registration,
-        resp_with_version.registration,
-    );
+    let balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(USER_ADDR), pay_denom)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(9950));
+    let balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(ADMIN_ADDR), pay_denom)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(10050));
+    let balance = app
+        .wrap()
+        .query_balance(contract.clone(), pay_denom)
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::zero());
 
-    // OTHER USER
-    let new_version: &str = "0.0.2";
-    let new_code_id: u64 = 2;
+    // Sending too much CW20 should succeed and refund the difference.
+    register_cw20(
+        &mut app,
+        contract.clone(),
+        coin(80, token.to_string()),
+        name.to_string(),
+        "0.0.2".to_string(),
+        2,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
 
-    // Register by other user fails with unauthorized.
-    let err: ContractError = register_native(
+    let balance = query_cw20_balance(&mut app, token.clone(), Addr::unchecked(USER_ADDR));
+    assert_eq!(balance, Uint128::new(950));
+    let balance = query_cw20_balance(&mut app, token.clone(), Addr::unchecked(ADMIN_ADDR));
+    assert_eq!(balance, Uint128::new(1050));
+    let balance = query_cw20_balance(&mut app, token, contract.clone());
+    assert_eq!(balance, Uint128::zero());
+
+    let registrations = query_list_registrations(&mut app, contract, name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(registrations.len(), 2);
+}
+
+#[test]
+fn test_fee_exemptions_and_overrides() {
+    let mut app = setup_app();
+    let juno_payment = PaymentInfo::NativePayment {
+        token_denom: "ujuno".to_string(),
+        payment_amount: Uint128::new(50),
+    };
+    let atom_payment = PaymentInfo::NativePayment {
+        token_denom: "uatom".to_string(),
+        payment_amount: Uint128::new(10),
+    };
+    let contract = setup_test_case(&mut app, vec![juno_payment.clone()]);
+    let name: &str = "Name";
+
+    // Give user address ownership over name.
+    set_owner(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
         name.to_string(),
-        new_version.to_string(),
-        new_code_id,
-        Addr::unchecked(OTHER_USER_ADDR),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
     )
-    .unwrap_err()
-    .downcast()
     .unwrap();
+
+    // Without overrides the configured payment applies.
+    let fee = query_get_fee(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        USER_ADDR.to_string(),
+    )
+    .unwrap();
+    assert_eq!(fee.payment_info, vec![juno_payment.clone()]);
+
+    // Overriding fees as non admin fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &ExecuteMsg::SetChainIdFee {
+                chain_id: CHAIN_ID.to_string(),
+                payment_info: Some(vec![]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(err, ContractError::Unauthorized {});
 
-    // Give other user address ownership over name.
-    set_owner(
+    // Make registering on the chain free.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetChainIdFee {
+            chain_id: CHAIN_ID.to_string(),
+            payment_info: Some(vec![]),
+        },
+        &[],
+    )
+    .unwrap();
+    let fee = query_get_fee(
         &mut app,
         contract.clone(),
         name.to_string(),
-        Some(OTHER_USER_ADDR.to_string()),
+        USER_ADDR.to_string(),
+    )
+    .unwrap();
+    assert_eq!(fee.payment_info, vec![]);
+
+    // Registering for free succeeds, and any funds sent are returned.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, "ujuno"),
+        name.to_string(),
+        "0.0.1".to_string(),
+        1,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap();
+    let balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(USER_ADDR), "ujuno")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(10000));
+
+    // A name override takes precedence over the chain override.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetNameFee {
+            name: name.to_string(),
+            payment_info: Some(vec![atom_payment.clone()]),
+        },
+        &[],
+    )
+    .unwrap();
+    let fee = query_get_fee(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        USER_ADDR.to_string(),
+    )
+    .unwrap();
+    assert_eq!(fee.payment_info, vec![atom_payment.clone()]);
 
-    // Register by original user fails with unauthorized.
     let err: ContractError = register_native(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
+        vec![],
         name.to_string(),
-        new_version.to_string(),
-        new_code_id,
+        "0.0.2".to_string(),
+        2,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(err, ContractError::Unauthorized {});
-
-    // Register by other user succeeds.
+    assert_eq!(err, ContractError::Payment(PaymentError::NoFunds {}));
     register_native(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
+        coins(10, "uatom"),
         name.to_string(),
-        new_version.to_string(),
-        new_code_id,
-        Addr::unchecked(OTHER_USER_ADDR),
+        "0.0.2".to_string(),
+        2,
+        Addr::unchecked(USER_ADDR),
     )
     .unwrap();
 
-    // Check registration with and without version.
-    let resp_without_version =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
-    let resp_with_version = query_get_registration(
+    // Exempt addresses register for free regardless of overrides.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::UpdateFeeExemptions {
+            to_add: vec![USER_ADDR.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let fee = query_get_fee(
         &mut app,
         contract.clone(),
         name.to_string(),
-        Some(new_version.to_string()),
+        USER_ADDR.to_string(),
     )
     .unwrap();
-    assert_eq!(
-        resp_without_version.registration,
-        Registration {
-            registered_by: Addr::unchecked(OTHER_USER_ADDR),
-            version: new_version.to_string(),
-            code_id: new_code_id,
-            checksum: new_version.to_string(),
-        }
-    );
-    assert_eq!(
-        resp_without_version.registration,
-        resp_with_version.registration,
-    );
+    assert_eq!(fee.payment_info, vec![]);
+    let fee = query_get_fee(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        OTHER_USER_ADDR.to_string(),
+    )
+    .unwrap();
+    assert_eq!(fee.payment_info, vec![atom_payment.clone()]);
 
-    // Get all registrations and verify both exist.
-    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
-        .unwrap()
-        .registrations;
-    assert_eq!(
-        registrations,
-        vec![
-            Registration {
-                registered_by: Addr::unchecked(USER_ADDR),
-                version: version.to_string(),
-                code_id,
-                checksum: version.to_string(),
-            },
-            Registration {
-                registered_by: Addr::unchecked(OTHER_USER_ADDR),
-                version: new_version.to_string(),
-                code_id: new_code_id,
-                checksum: new_version.to_string(),
-            }
-        ]
-    );
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "0.0.3".to_string(),
+        3,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
 
-    // Get info for each code ID.
-    let info = query_info_for_code_id(&mut app, contract.clone(), code_id).unwrap();
-    assert_eq!(
-        info,
-        InfoForCodeIdResponse {
-            registered_by: Addr::unchecked(USER_ADDR),
-            name: name.to_string(),
-            version: version.to_string(),
-            checksum: version.to_string(),
-        }
-    );
-    let new_info = query_info_for_code_id(&mut app, contract, new_code_id).unwrap();
-    assert_eq!(
-        new_info,
-        InfoForCodeIdResponse {
-            registered_by: Addr::unchecked(OTHER_USER_ADDR),
+    // Removing the exemption and overrides restores the configured payment.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::UpdateFeeExemptions {
+            to_add: vec![],
+            to_remove: vec![USER_ADDR.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetNameFee {
             name: name.to_string(),
-            version: new_version.to_string(),
-            checksum: new_version.to_string(),
-        }
-    );
+            payment_info: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetChainIdFee {
+            chain_id: CHAIN_ID.to_string(),
+            payment_info: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let fee = query_get_fee(&mut app, contract, name.to_string(), USER_ADDR.to_string()).unwrap();
+    assert_eq!(fee.payment_info, vec![juno_payment]);
 }
 
 #[test]
-fn test_unregister() {
+fn test_immutability() {
     let mut app = setup_app();
     let pay_denom = "ujuno";
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::NativePayment {
+        vec![PaymentInfo::NativePayment {
             token_denom: pay_denom.to_string(),
             payment_amount: Uint128::new(50),
-        },
+        }],
     );
     let name: &str = "Name";
-    let version1: &str = "0.0.1";
-    let code_id1: u64 = 1;
-    let version2: &str = "0.0.2";
-    let code_id2: u64 = 2;
-    let version3: &str = "0.0.3";
-    let code_id3: u64 = 3;
-
-    let reg1 = Registration {
-        registered_by: Addr::unchecked(USER_ADDR),
-        version: version1.to_string(),
-        code_id: code_id1,
-        checksum: version1.to_string(),
-    };
-    let reg2 = Registration {
-        registered_by: Addr::unchecked(USER_ADDR),
-        version: version2.to_string(),
-        code_id: code_id2,
-        checksum: version2.to_string(),
-    };
-    let reg3 = Registration {
-        registered_by: Addr::unchecked(USER_ADDR),
-        version: version3.to_string(),
-        code_id: code_id3,
-        checksum: version3.to_string(),
-    };
+    let version: &str = "0.0.1";
+    let code_id: u64 = 1;
 
     // Give user address ownership over name.
     set_owner(
@@ -947,156 +1139,116 @@ fn test_unregister() {
     )
     .unwrap();
 
-    // Register 1 succeeds.
+    // Register.
     register_native(
         &mut app,
         contract.clone(),
         coins(50, pay_denom),
         name.to_string(),
-        version1.to_string(),
-        code_id1,
+        version.to_string(),
+        code_id,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap();
 
-    // Register 2 succeeds.
-    register_native(
+    // Check registration with and without version.
+    let resp_without_version =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
+    let resp_with_version = query_get_registration(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
         name.to_string(),
-        version2.to_string(),
-        code_id2,
-        Addr::unchecked(USER_ADDR),
+        Some(version.to_string()),
     )
     .unwrap();
-
-    // Register 3 succeeds.
-    register_native(
+    assert_eq!(
+        resp_without_version.registration,
+        Registration {
+            registered_by: Addr::unchecked(USER_ADDR),
+            version: version.to_string(),
+            code_id,
+            checksum: version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
+        }
+    );
+    assert_eq!(
+        resp_without_version.registration,
+        resp_with_version.registration,
+    );
+
+    // Should fail with Code ID already registered.
+    let err: ContractError = register_native(
         &mut app,
         contract.clone(),
         coins(50, pay_denom),
         name.to_string(),
-        version3.to_string(),
-        code_id3,
+        version.to_string(),
+        code_id,
         Addr::unchecked(USER_ADDR),
     )
+    .unwrap_err()
+    .downcast()
     .unwrap();
-
-    // Get all registrations and verify all exist.
-    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
-        .unwrap()
-        .registrations;
     assert_eq!(
-        registrations,
-        vec![reg1.clone(), reg2.clone(), reg3.clone()]
+        err,
+        ContractError::CodeIDAlreadyRegistered(code_id, CHAIN_ID.to_string())
     );
 
-    // Get latest and ensure it is 3.
-    let latest_registration =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None)
-            .unwrap()
-            .registration;
-    assert_eq!(latest_registration, reg3);
-
-    // Attempt unregister 3 by user but fail because not admin.
-    let err: ContractError = unregister(
+    // Should fail with version already registered.
+    let err: ContractError = register_native(
         &mut app,
-        contract.clone(),
+        contract,
+        coins(50, pay_denom),
         name.to_string(),
-        code_id3,
+        version.to_string(),
+        code_id + 1,
         Addr::unchecked(USER_ADDR),
     )
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(err, ContractError::Unauthorized {});
-
-    // Unregister 3
-    unregister(
-        &mut app,
-        contract.clone(),
-        name.to_string(),
-        code_id3,
-        Addr::unchecked(ADMIN_ADDR),
-    )
-    .unwrap();
-
-    // Attempt to get info for 3 and expect not found.
-    let err = query_info_for_code_id(&mut app, contract.clone(), code_id3).unwrap_err();
-    assert!(err
-        .to_string()
-        .contains(&ContractError::NotFound {}.to_string()));
-    // Attempt to get registration for 3 and expect not found.
-    let err = query_get_registration(
-        &mut app,
-        contract.clone(),
-        name.to_string(),
-        Some(version3.to_string()),
-    )
-    .unwrap_err();
-    assert!(err
-        .to_string()
-        .contains(&ContractError::NotFound {}.to_string()));
-
-    // Get latest and ensure it is 2.
-    let latest_registration =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None)
-            .unwrap()
-            .registration;
-    assert_eq!(latest_registration, reg2);
-
-    // Get all registrations and verify only 1 and 2 exist.
-    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
-        .unwrap()
-        .registrations;
-    assert_eq!(registrations, vec![reg1, reg2]);
-
-    // Unregister 1 and 2
-    unregister(
-        &mut app,
-        contract.clone(),
-        name.to_string(),
-        code_id1,
-        Addr::unchecked(ADMIN_ADDR),
-    )
-    .unwrap();
-    unregister(
-        &mut app,
-        contract.clone(),
-        name.to_string(),
-        code_id2,
-        Addr::unchecked(ADMIN_ADDR),
-    )
-    .unwrap();
-
-    // Expect not found when attempting to get latest.
-    let err =
-        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap_err();
-    assert!(err
-        .to_string()
-        .contains(&ContractError::NotFound {}.to_string()));
-
-    // Get all registrations and expect empty list found.
-    let response = query_list_registrations(&mut app, contract, name.to_string()).unwrap();
-    assert_eq!(response.registrations.len(), 0);
+    assert_eq!(
+        err,
+        ContractError::VersionAlreadyRegistered(
+            version.to_string(),
+            name.to_string(),
+            CHAIN_ID.to_string()
+        )
+    );
 }
 
 #[test]
-fn test_mutable_after_unregister() {
+fn test_set_owner() {
     let mut app = setup_app();
     let pay_denom = "ujuno";
     let contract = setup_test_case(
         &mut app,
-        PaymentInfo::NativePayment {
+        vec![PaymentInfo::NativePayment {
             token_denom: pay_denom.to_string(),
             payment_amount: Uint128::new(50),
-        },
+        }],
     );
     let name: &str = "Name";
     let version: &str = "0.0.1";
     let code_id: u64 = 1;
 
+    // Register fails with unauthorized.
+    let err: ContractError = register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
     // Give user address ownership over name.
     set_owner(
         &mut app,
@@ -1107,7 +1259,7 @@ fn test_mutable_after_unregister() {
     )
     .unwrap();
 
-    // Register.
+    // Register succeeds.
     register_native(
         &mut app,
         contract.clone(),
@@ -1136,6 +1288,9 @@ fn test_mutable_after_unregister() {
             version: version.to_string(),
             code_id,
             checksum: version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
         }
     );
     assert_eq!(
@@ -1143,228 +1298,4651 @@ fn test_mutable_after_unregister() {
         resp_with_version.registration,
     );
 
-    // Should fail with Code ID already registered.
-    let err: ContractError = register_native(
-        &mut app,
-        contract.clone(),
-        coins(50, pay_denom),
-        name.to_string(),
-        version.to_string(),
-        code_id,
-        Addr::unchecked(USER_ADDR),
-    )
-    .unwrap_err()
-    .downcast()
-    .unwrap();
-    assert_eq!(
-        err,
-        ContractError::CodeIDAlreadyRegistered(code_id, CHAIN_ID.to_string())
-    );
+    // OTHER USER
+    let new_version: &str = "0.0.2";
+    let new_code_id: u64 = 2;
 
-    // Should fail with version already registered.
+    // Register by other user fails with unauthorized.
     let err: ContractError = register_native(
         &mut app,
         contract.clone(),
         coins(50, pay_denom),
         name.to_string(),
-        version.to_string(),
-        code_id + 1,
-        Addr::unchecked(USER_ADDR),
+        new_version.to_string(),
+        new_code_id,
+        Addr::unchecked(OTHER_USER_ADDR),
     )
     .unwrap_err()
     .downcast()
     .unwrap();
-    assert_eq!(
-        err,
-        ContractError::VersionAlreadyRegistered(
-            version.to_string(),
-            name.to_string(),
-            CHAIN_ID.to_string()
-        )
-    );
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    // Unregister
-    unregister(
+    // Give other user address ownership over name.
+    set_owner(
         &mut app,
         contract.clone(),
         name.to_string(),
-        code_id,
-        Addr::unchecked(ADMIN_ADDR),
+        Some(OTHER_USER_ADDR.to_string()),
+        Addr::unchecked(USER_ADDR),
     )
     .unwrap();
 
-    // Should NOT fail with Code ID already registered.
-    register_native(
+    // Register by original user fails with unauthorized.
+    let err: ContractError = register_native(
         &mut app,
         contract.clone(),
         coins(50, pay_denom),
         name.to_string(),
-        version.to_string(),
-        code_id,
+        new_version.to_string(),
+        new_code_id,
         Addr::unchecked(USER_ADDR),
     )
+    .unwrap_err()
+    .downcast()
     .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
 
-    // Unregister
-    unregister(
+    // Register by other user succeeds.
+    register_native(
         &mut app,
         contract.clone(),
+        coins(50, pay_denom),
         name.to_string(),
-        code_id,
-        Addr::unchecked(ADMIN_ADDR),
+        new_version.to_string(),
+        new_code_id,
+        Addr::unchecked(OTHER_USER_ADDR),
     )
     .unwrap();
 
-    // Should NOT fail with version already registered.
-    register_native(
+    // Check registration with and without version.
+    let resp_without_version =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
+    let resp_with_version = query_get_registration(
         &mut app,
         contract.clone(),
-        coins(50, pay_denom),
         name.to_string(),
-        version.to_string(),
-        code_id + 1,
-        Addr::unchecked(USER_ADDR),
+        Some(new_version.to_string()),
     )
     .unwrap();
-
-    // Get info for new code ID.
-    let info = query_info_for_code_id(&mut app, contract, code_id + 1).unwrap();
     assert_eq!(
-        info,
-        InfoForCodeIdResponse {
-            registered_by: Addr::unchecked(USER_ADDR),
-            name: name.to_string(),
-            version: version.to_string(),
-            checksum: version.to_string(),
+        resp_without_version.registration,
+        Registration {
+            registered_by: Addr::unchecked(OTHER_USER_ADDR),
+            version: new_version.to_string(),
+            code_id: new_code_id,
+            checksum: new_version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
         }
     );
-}
-
-#[test]
-fn test_update_config() {
-    let mut app = setup_app();
-    let token = create_token(&mut app);
-    let names = setup_test_case(
-        &mut app,
-        PaymentInfo::Cw20Payment {
-            token_address: token.to_string(),
-            payment_amount: Uint128::new(50),
-        },
+    assert_eq!(
+        resp_without_version.registration,
+        resp_with_version.registration,
     );
-    let other_token = create_token(&mut app); // To be used when updating payment token
 
-    let config = query_config(&mut app, names.clone());
+    // Get all registrations and verify both exist.
+    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(
+        registrations,
+        vec![
+            Registration {
+                registered_by: Addr::unchecked(USER_ADDR),
+                version: version.to_string(),
+                code_id,
+                checksum: version.to_string(),
+                prerelease: false,
+                dependencies: vec![],
+                yanked: false,
+            },
+            Registration {
+                registered_by: Addr::unchecked(OTHER_USER_ADDR),
+                version: new_version.to_string(),
+                code_id: new_code_id,
+                checksum: new_version.to_string(),
+                prerelease: false,
+                dependencies: vec![],
+                yanked: false,
+            }
+        ]
+    );
+
+    // Get info for each code ID.
+    let info = query_info_for_code_id(&mut app, contract.clone(), code_id).unwrap();
+    assert_eq!(
+        info,
+        InfoForCodeIdResponse {
+            registered_by: Addr::unchecked(USER_ADDR),
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: version.to_string(),
+            source: None,
+        }
+    );
+    let new_info = query_info_for_code_id(&mut app, contract, new_code_id).unwrap();
+    assert_eq!(
+        new_info,
+        InfoForCodeIdResponse {
+            registered_by: Addr::unchecked(OTHER_USER_ADDR),
+            name: name.to_string(),
+            version: new_version.to_string(),
+            checksum: new_version.to_string(),
+            source: None,
+        }
+    );
+}
+
+#[test]
+fn test_unregister() {
+    let mut app = setup_app();
+    let pay_denom = "ujuno";
+    let contract = setup_test_case(
+        &mut app,
+        vec![PaymentInfo::NativePayment {
+            token_denom: pay_denom.to_string(),
+            payment_amount: Uint128::new(50),
+        }],
+    );
+    let name: &str = "Name";
+    let version1: &str = "0.0.1";
+    let code_id1: u64 = 1;
+    let version2: &str = "0.0.2";
+    let code_id2: u64 = 2;
+    let version3: &str = "0.0.3";
+    let code_id3: u64 = 3;
+
+    let reg1 = Registration {
+        registered_by: Addr::unchecked(USER_ADDR),
+        version: version1.to_string(),
+        code_id: code_id1,
+        checksum: version1.to_string(),
+        prerelease: false,
+        dependencies: vec![],
+        yanked: false,
+    };
+    let reg2 = Registration {
+        registered_by: Addr::unchecked(USER_ADDR),
+        version: version2.to_string(),
+        code_id: code_id2,
+        checksum: version2.to_string(),
+        prerelease: false,
+        dependencies: vec![],
+        yanked: false,
+    };
+    let reg3 = Registration {
+        registered_by: Addr::unchecked(USER_ADDR),
+        version: version3.to_string(),
+        code_id: code_id3,
+        checksum: version3.to_string(),
+        prerelease: false,
+        dependencies: vec![],
+        yanked: false,
+    };
+
+    // Give user address ownership over name.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Register 1 succeeds.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version1.to_string(),
+        code_id1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Register 2 succeeds.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version2.to_string(),
+        code_id2,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Register 3 succeeds.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version3.to_string(),
+        code_id3,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Get all registrations and verify all exist.
+    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(
+        registrations,
+        vec![reg1.clone(), reg2.clone(), reg3.clone()]
+    );
+
+    // Get latest and ensure it is 3.
+    let latest_registration =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None)
+            .unwrap()
+            .registration;
+    assert_eq!(latest_registration, reg3);
+
+    // Attempt unregister 3 by user but fail because not admin.
+    let err: ContractError = unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id3,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Unregister 3
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id3,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Attempt to get info for 3 and expect not found.
+    let err = query_info_for_code_id(&mut app, contract.clone(), code_id3).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+    // Attempt to get registration for 3 and expect not found.
+    let err = query_get_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(version3.to_string()),
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+
+    // Get latest and ensure it is 2.
+    let latest_registration =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None)
+            .unwrap()
+            .registration;
+    assert_eq!(latest_registration, reg2);
+
+    // Get all registrations and verify only 1 and 2 exist.
+    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(registrations, vec![reg1, reg2]);
+
+    // Unregister 1 and 2
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id2,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Expect not found when attempting to get latest.
+    let err =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+
+    // Get all registrations and expect empty list found.
+    let response = query_list_registrations(&mut app, contract, name.to_string()).unwrap();
+    assert_eq!(response.registrations.len(), 0);
+}
+
+#[test]
+fn test_mutable_after_unregister() {
+    let mut app = setup_app();
+    let pay_denom = "ujuno";
+    let contract = setup_test_case(
+        &mut app,
+        vec![PaymentInfo::NativePayment {
+            token_denom: pay_denom.to_string(),
+            payment_amount: Uint128::new(50),
+        }],
+    );
+    let name: &str = "Name";
+    let version: &str = "0.0.1";
+    let code_id: u64 = 1;
+
+    // Give user address ownership over name.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Register.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Check registration with and without version.
+    let resp_without_version =
+        query_get_registration(&mut app, contract.clone(), name.to_string(), None).unwrap();
+    let resp_with_version = query_get_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(version.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        resp_without_version.registration,
+        Registration {
+            registered_by: Addr::unchecked(USER_ADDR),
+            version: version.to_string(),
+            code_id,
+            checksum: version.to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
+        }
+    );
+    assert_eq!(
+        resp_without_version.registration,
+        resp_with_version.registration,
+    );
+
+    // Should fail with Code ID already registered.
+    let err: ContractError = register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::CodeIDAlreadyRegistered(code_id, CHAIN_ID.to_string())
+    );
+
+    // Should fail with version already registered.
+    let err: ContractError = register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id + 1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VersionAlreadyRegistered(
+            version.to_string(),
+            name.to_string(),
+            CHAIN_ID.to_string()
+        )
+    );
+
+    // Unregister
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Should NOT fail with Code ID already registered.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Unregister
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        code_id,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Should NOT fail with version already registered.
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(50, pay_denom),
+        name.to_string(),
+        version.to_string(),
+        code_id + 1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Get info for new code ID.
+    let info = query_info_for_code_id(&mut app, contract, code_id + 1).unwrap();
+    assert_eq!(
+        info,
+        InfoForCodeIdResponse {
+            registered_by: Addr::unchecked(USER_ADDR),
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: version.to_string(),
+            source: None,
+        }
+    );
+}
+
+#[test]
+fn test_update_config() {
+    let mut app = setup_app();
+    let token = create_token(&mut app);
+    let names = setup_test_case(
+        &mut app,
+        vec![PaymentInfo::Cw20Payment {
+            token_address: token.to_string(),
+            payment_amount: Uint128::new(50),
+        }],
+    );
+    let other_token = create_token(&mut app); // To be used when updating payment token
+
+    let config = query_config(&mut app, names.clone());
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(ADMIN_ADDR),
+            payment_info: vec![PaymentInfo::Cw20Payment {
+                token_address: token.to_string(),
+                payment_amount: Uint128::new(50)
+            }],
+            fee_recipients: vec![],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+
+    // Update config as non admin fails
+    let err: ContractError = update_config(
+        &mut app,
+        names.clone(),
+        Some(other_token.to_string()),
+        Some(vec![PaymentInfo::NativePayment {
+            token_denom: "ujuno".to_string(),
+            payment_amount: Uint128::new(50),
+        }]),
+        None,
+        Addr::unchecked(OTHER_USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Ensure config stayed the same.
+    let config = query_config(&mut app, names.clone());
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(ADMIN_ADDR),
+            payment_info: vec![PaymentInfo::Cw20Payment {
+                token_address: token.to_string(),
+                payment_amount: Uint128::new(50)
+            }],
+            fee_recipients: vec![],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+
+    // Update config as admin
+    update_config(
+        &mut app,
+        names.clone(),
+        Some(OTHER_USER_ADDR.to_string()),
+        Some(vec![PaymentInfo::NativePayment {
+            token_denom: "ujuno".to_string(),
+            payment_amount: Uint128::new(25),
+        }]),
+        None,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let config = query_config(&mut app, names.clone());
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(OTHER_USER_ADDR),
+            payment_info: vec![PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(25)
+            }],
+            fee_recipients: vec![],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+
+    // Update one config value but not the others
+
+    // Only admin
+    update_config(
+        &mut app,
+        names.clone(),
+        Some(ADMIN_ADDR.to_string()),
+        None,
+        None,
+        Addr::unchecked(OTHER_USER_ADDR),
+    )
+    .unwrap();
+
+    let config = query_config(&mut app, names.clone());
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(ADMIN_ADDR), // Only this has changed
+            payment_info: vec![PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(25)
+            }],
+            fee_recipients: vec![],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+
+    // Only payment info
+    update_config(
+        &mut app,
+        names.clone(),
+        None,
+        Some(vec![PaymentInfo::NativePayment {
+            token_denom: "uatom".to_string(),
+            payment_amount: Uint128::new(50),
+        }]),
+        None,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let config = query_config(&mut app, names.clone());
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(ADMIN_ADDR),
+            payment_info: vec![PaymentInfo::NativePayment {
+                token_denom: "uatom".to_string(),
+                payment_amount: Uint128::new(50)
+            }],
+            fee_recipients: vec![],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+    // Shares that don't sum to one fail.
+    let err: ContractError = update_config(
+        &mut app,
+        names.clone(),
+        None,
+        None,
+        Some(vec![
+            (USER_ADDR.to_string(), Decimal::percent(50)),
+            (OTHER_USER_ADDR.to_string(), Decimal::percent(40)),
+        ]),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::InvalidFeeShares {});
+
+    // Zero shares fail.
+    let err: ContractError = update_config(
+        &mut app,
+        names.clone(),
+        None,
+        None,
+        Some(vec![
+            (USER_ADDR.to_string(), Decimal::one()),
+            (OTHER_USER_ADDR.to_string(), Decimal::zero()),
+        ]),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::InvalidFeeShares {});
+
+    // Only fee recipients
+    update_config(
+        &mut app,
+        names.clone(),
+        None,
+        None,
+        Some(vec![
+            (USER_ADDR.to_string(), Decimal::percent(60)),
+            (OTHER_USER_ADDR.to_string(), Decimal::percent(40)),
+        ]),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let config = query_config(&mut app, names);
+    assert_eq!(
+        config,
+        Config {
+            admin: Addr::unchecked(ADMIN_ADDR),
+            payment_info: vec![PaymentInfo::NativePayment {
+                token_denom: "uatom".to_string(),
+                payment_amount: Uint128::new(50)
+            }],
+            fee_recipients: vec![
+                (Addr::unchecked(USER_ADDR), Decimal::percent(60)),
+                (Addr::unchecked(OTHER_USER_ADDR), Decimal::percent(40)),
+            ],
+            validate_chain_ids: false,
+            upstreams: vec![],
+            unregister_window: None,
+            refund_on_unregister: false,
+            immutable: false,
+            rate_limit: None,
+            deposit: None,
+            hook_gas_limit: DEFAULT_HOOK_GAS_LIMIT,
+        }
+    );
+}
+
+#[test]
+fn test_fee_recipients() {
+    let mut app = setup_app();
+    let token = create_token(&mut app);
+    let pay_denom = "ujuno";
+    let contract = setup_test_case(
+        &mut app,
+        vec![PaymentInfo::NativePayment {
+            token_denom: pay_denom.to_string(),
+            payment_amount: Uint128::new(51),
+        }],
+    );
+    let name: &str = "Name";
+
+    // Split fees between two recipients. 51 does not divide evenly, so the
+    // dust should go to the first recipient.
+    update_config(
+        &mut app,
+        contract.clone(),
+        None,
+        None,
+        Some(vec![
+            (USER_ADDR.to_string(), Decimal::percent(50)),
+            (OTHER_USER_ADDR.to_string(), Decimal::percent(50)),
+        ]),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    register_native(
+        &mut app,
+        contract.clone(),
+        coins(51, pay_denom),
+        name.to_string(),
+        "0.0.1".to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let user_balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(USER_ADDR), pay_denom)
+        .unwrap();
+    assert_eq!(user_balance.amount, Uint128::new(10026));
+    let other_user_balance = app
+        .wrap()
+        .query_balance(Addr::unchecked(OTHER_USER_ADDR), pay_denom)
+        .unwrap();
+    assert_eq!(other_user_balance.amount, Uint128::new(10025));
+
+    // Switch to a CW20 payment and check it is split too.
+    update_config(
+        &mut app,
+        contract.clone(),
+        None,
+        Some(vec![PaymentInfo::Cw20Payment {
+            token_address: token.to_string(),
+            payment_amount: Uint128::new(100),
+        }]),
+        Some(vec![
+            (USER_ADDR.to_string(), Decimal::percent(70)),
+            (OTHER_USER_ADDR.to_string(), Decimal::percent(30)),
+        ]),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    register_cw20(
+        &mut app,
+        contract,
+        coin(100, token.to_string()),
+        name.to_string(),
+        "0.0.2".to_string(),
+        2,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let balance = query_cw20_balance(&mut app, token.clone(), Addr::unchecked(ADMIN_ADDR));
+    assert_eq!(balance, Uint128::new(900));
+    let balance = query_cw20_balance(&mut app, token.clone(), Addr::unchecked(USER_ADDR));
+    assert_eq!(balance, Uint128::new(1070));
+    let balance = query_cw20_balance(&mut app, token, Addr::unchecked(OTHER_USER_ADDR));
+    assert_eq!(balance, Uint128::new(1030));
+}
+
+#[test]
+fn test_pause() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+    let name: &str = "Name";
+
+    // Give user address ownership over name.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Pausing as non admin fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &ExecuteMsg::Pause { until: None },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Pausing until a past height fails.
+    let height = app.block_info().height;
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            contract.clone(),
+            &ExecuteMsg::Pause {
+                until: Some(Expiration::AtHeight(height)),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidPauseExpiration {});
+
+    // Pause for 10 blocks.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Pause {
+            until: Some(Expiration::AtHeight(height + 10)),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_pause_status(&mut app, contract.clone()),
+        PauseStatusResponse {
+            paused: true,
+            until: Some(Expiration::AtHeight(height + 10)),
+        }
+    );
+
+    // Registering and setting owners fail while paused.
+    let err: ContractError = register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "0.0.1".to_string(),
+        1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+    let err: ContractError = set_owner(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(OTHER_USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+
+    // Admin can still update config while paused, and queries still work.
+    update_config(
+        &mut app,
+        contract.clone(),
+        None,
+        Some(vec![]),
+        None,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    query_list_registrations(&mut app, contract.clone(), name.to_string()).unwrap();
+
+    // Once the pause expires, registering succeeds.
+    app.update_block(|block| block.height += 10);
+    assert_eq!(
+        query_pause_status(&mut app, contract.clone()),
+        PauseStatusResponse {
+            paused: false,
+            until: None,
+        }
+    );
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "0.0.1".to_string(),
+        1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Pause indefinitely.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Pause { until: None },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_pause_status(&mut app, contract.clone()),
+        PauseStatusResponse {
+            paused: true,
+            until: Some(Expiration::Never {}),
+        }
+    );
+
+    // Only the admin may unregister while paused, even within the owner's
+    // unregister window.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetUnregisterWindow {
+            window: Some(Duration::Height(10)),
+            refund: false,
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        1,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Paused {});
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Unpausing as non admin fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Unpause and register.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+    register_native(
+        &mut app,
+        contract,
+        vec![],
+        name.to_string(),
+        "0.0.2".to_string(),
+        2,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_tags() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+    let name: &str = "Name";
+
+    // Give user address ownership over name.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    for (version, code_id) in [("0.0.1", 1), ("0.0.2", 2), ("0.0.3", 3)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(USER_ADDR),
+        )
+        .unwrap();
+    }
+
+    // Setting a tag as non owner fails.
+    let err: ContractError = set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "stable".to_string(),
+        "0.0.1".to_string(),
+        Addr::unchecked(OTHER_USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Tagging an unregistered version fails.
+    let err: ContractError = set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "stable".to_string(),
+        "1.0.0".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::NotFound {});
+
+    // Latest can't be set explicitly.
+    let err: ContractError = set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "latest".to_string(),
+        "0.0.1".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::InvalidTag("latest".to_string()));
+
+    // Owner and admin can set tags.
+    set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "stable".to_string(),
+        "0.0.1".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "beta".to_string(),
+        "0.0.2".to_string(),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let stable = query_get_tagged_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "stable".to_string(),
+    )
+    .unwrap();
+    assert_eq!(stable.registration.code_id, 1);
+    let beta = query_get_tagged_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "beta".to_string(),
+    )
+    .unwrap();
+    assert_eq!(beta.registration.code_id, 2);
+    assert_eq!(
+        query_list_tags(&mut app, contract.clone(), name.to_string()).tags,
+        vec![
+            ("beta".to_string(), "0.0.2".to_string()),
+            ("stable".to_string(), "0.0.1".to_string()),
+        ]
+    );
+
+    // Moving a tag points it at the new version.
+    set_tag(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "beta".to_string(),
+        "0.0.3".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    let beta = query_get_tagged_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "beta".to_string(),
+    )
+    .unwrap();
+    assert_eq!(beta.registration.code_id, 3);
+
+    // Removing a tag.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::RemoveTag {
+            name: name.to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            tag: "beta".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = query_get_tagged_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        "beta".to_string(),
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+
+    // Unregistering a version removes tags pointing at it.
+    unregister(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    let response = query_list_tags(&mut app, contract, name.to_string());
+    assert_eq!(response.tags.len(), 0);
+}
+
+#[test]
+fn test_prerelease() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+    let name: &str = "Name";
+
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "v1.0.0".to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "v1.1.0-rc.1".to_string(),
+        2,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Latest skips the pre-release by default.
+    let latest = query_get_registration(&mut app, contract.clone(), name.to_string(), None)
+        .unwrap()
+        .registration;
+    assert_eq!(latest.code_id, 1);
+    assert!(!latest.prerelease);
+
+    // Latest includes the pre-release when opted in.
+    let latest: GetRegistrationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::GetRegistration {
+                name: name.to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                version: None,
+                include_prerelease: Some(true),
+            },
+        )
+        .unwrap();
+    assert_eq!(latest.registration.code_id, 2);
+    assert!(latest.registration.prerelease);
+
+    // Pre-releases can always be requested by version.
+    let rc = query_get_registration(
+        &mut app,
+        contract.clone(),
+        name.to_string(),
+        Some("v1.1.0-rc.1".to_string()),
+    )
+    .unwrap()
+    .registration;
+    assert_eq!(rc.code_id, 2);
+
+    // Listing skips pre-releases unless opted in.
+    let registrations = query_list_registrations(&mut app, contract.clone(), name.to_string())
+        .unwrap()
+        .registrations;
+    assert_eq!(registrations.len(), 1);
+    let response: ListRegistrationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::ListRegistrations {
+                name: name.to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                include_prerelease: Some(true),
+            },
+        )
+        .unwrap();
+    assert_eq!(response.registrations.len(), 2);
+
+    // Hyphens in build metadata do not make a pre-release.
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        name.to_string(),
+        "v1.0.1+build-5".to_string(),
+        3,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    let latest = query_get_registration(&mut app, contract, name.to_string(), None)
+        .unwrap()
+        .registration;
+    assert_eq!(latest.code_id, 3);
+    assert!(!latest.prerelease);
+}
+
+#[test]
+fn test_rename_and_alias() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+    let old_name: &str = "cw-proposal-single";
+    let new_name: &str = "dao-proposal-single";
+
+    // Give user address ownership over old name, register and tag.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        old_name.to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    for (version, code_id) in [("0.0.1", 1), ("0.0.2", 2)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            old_name.to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(USER_ADDR),
+        )
+        .unwrap();
+    }
+    set_tag(
+        &mut app,
+        contract.clone(),
+        old_name.to_string(),
+        "stable".to_string(),
+        "0.0.1".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Renaming as non owner fails.
+    let rename = ExecuteMsg::Rename {
+        name: old_name.to_string(),
+        chain_id: CHAIN_ID.to_string(),
+        new_name: new_name.to_string(),
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OTHER_USER_ADDR),
+            contract.clone(),
+            &rename,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Renaming to a name that is in use fails.
+    set_owner(
+        &mut app,
+        contract.clone(),
+        "taken".to_string(),
+        Some(OTHER_USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &ExecuteMsg::Rename {
+                name: old_name.to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                new_name: "taken".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NameInUse("taken".to_string()));
+
+    // Rename.
+    app.execute_contract(Addr::unchecked(USER_ADDR), contract.clone(), &rename, &[])
+        .unwrap();
+
+    // Lookups of the old name redirect to the new name and keep history.
+    let response =
+        query_get_registration(&mut app, contract.clone(), old_name.to_string(), None).unwrap();
+    assert_eq!(response.name, new_name.to_string());
+    assert_eq!(response.redirected_from, Some(old_name.to_string()));
+    assert_eq!(response.registration.code_id, 2);
+    let response = query_get_registration(
+        &mut app,
+        contract.clone(),
+        old_name.to_string(),
+        Some("0.0.1".to_string()),
+    )
+    .unwrap();
+    assert_eq!(response.registration.code_id, 1);
+    assert_eq!(
+        response.registration.registered_by,
+        Addr::unchecked(USER_ADDR)
+    );
+    let response = query_get_tagged_registration(
+        &mut app,
+        contract.clone(),
+        old_name.to_string(),
+        "stable".to_string(),
+    )
+    .unwrap();
+    assert_eq!(response.name, new_name.to_string());
+    assert_eq!(response.registration.code_id, 1);
+
+    // Lookups of the new name are not redirected.
+    let response =
+        query_get_registration(&mut app, contract.clone(), new_name.to_string(), None).unwrap();
+    assert_eq!(response.redirected_from, None);
+    let info = query_info_for_code_id(&mut app, contract.clone(), 1).unwrap();
+    assert_eq!(info.name, new_name.to_string());
+
+    // Listing through the old name lists the new name.
+    let response =
+        query_list_registrations(&mut app, contract.clone(), old_name.to_string()).unwrap();
+    assert_eq!(response.registrations.len(), 2);
+    let response = query_list_tags(&mut app, contract.clone(), old_name.to_string());
+    assert_eq!(
+        response.tags,
+        vec![("stable".to_string(), "0.0.1".to_string())]
+    );
+
+    // Messages through the old name manage the new name.
+    set_tag(
+        &mut app,
+        contract.clone(),
+        old_name.to_string(),
+        "beta".to_string(),
+        "0.0.2".to_string(),
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+    let response = query_list_tags(&mut app, contract.clone(), new_name.to_string());
+    assert_eq!(
+        response.tags,
+        vec![
+            ("beta".to_string(), "0.0.2".to_string()),
+            ("stable".to_string(), "0.0.1".to_string()),
+        ]
+    );
+
+    // Registering under the old name fails, even for admin.
+    let err: ContractError = register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        old_name.to_string(),
+        "0.0.3".to_string(),
+        3,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::NameIsAlias(old_name.to_string()));
+
+    // Ownership moved to the new name.
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        new_name.to_string(),
+        "0.0.3".to_string(),
+        3,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Aliasing a name that is in use fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &ExecuteMsg::SetAlias {
+                name: new_name.to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                alias: "taken".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NameInUse("taken".to_string()));
+
+    // Add and remove an alias.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetAlias {
+            name: new_name.to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            alias: "proposal-single".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let response = query_get_registration(
+        &mut app,
+        contract.clone(),
+        "proposal-single".to_string(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(response.name, new_name.to_string());
+    assert_eq!(response.registration.code_id, 3);
+
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::RemoveAlias {
+            alias: "proposal-single".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = query_get_registration(&mut app, contract, "proposal-single".to_string(), None)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+}
+
+fn juno_chain() -> Chain {
+    Chain {
+        chain_id: CHAIN_ID.to_string(),
+        name: "Juno".to_string(),
+        bech32_prefix: "juno".to_string(),
+        network_type: NetworkType::Mainnet,
+        aliases: vec!["juno".to_string()],
+    }
+}
+
+#[test]
+fn test_chain_catalogue() {
+    let mut app = setup_app();
+    let names = setup_test_case(&mut app, vec![]);
+
+    // Only admin can manage chains.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            names.clone(),
+            &ExecuteMsg::SetChain {
+                chain: juno_chain(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        names.clone(),
+        &ExecuteMsg::SetChain {
+            chain: juno_chain(),
+        },
+        &[],
+    )
+    .unwrap();
+    let testnet = Chain {
+        chain_id: "uni-5".to_string(),
+        name: "Juno Testnet".to_string(),
+        bech32_prefix: "juno".to_string(),
+        network_type: NetworkType::Testnet,
+        aliases: vec![],
+    };
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        names.clone(),
+        &ExecuteMsg::SetChain {
+            chain: testnet.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Aliases can't collide with other chains.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            names.clone(),
+            &ExecuteMsg::SetChain {
+                chain: Chain {
+                    aliases: vec!["juno".to_string()],
+                    ..testnet.clone()
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ChainIdInUse("juno".to_string()));
+
+    // Lookup by alias resolves to the canonical chain.
+    let res: GetChainResponse = app
+        .wrap()
+        .query_wasm_smart(
+            names.clone(),
+            &QueryMsg::GetChain {
+                chain_id: "juno".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.chain, juno_chain());
+
+    let res: ListChainsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            names.clone(),
+            &QueryMsg::ListChains {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.chains, vec![juno_chain(), testnet.clone()]);
+    let res: ListChainsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            names.clone(),
+            &QueryMsg::ListChains {
+                start_after: Some(CHAIN_ID.to_string()),
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.chains, vec![testnet]);
+
+    // Enable validation.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        names.clone(),
+        &ExecuteMsg::UpdateConfig {
+            admin: None,
+            payment_info: None,
+            fee_recipients: None,
+            validate_chain_ids: Some(true),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let register = |chain_id: &str| ExecuteMsg::Register {
+        name: "cw-plus".to_string(),
+        version: "0.9.0".to_string(),
+        chain_id: chain_id.to_string(),
+        code_id: 1,
+        checksum: "checksum".to_string(),
+        dependencies: None,
+    };
+
+    // Unknown chain IDs and aliases are rejected.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            names.clone(),
+            &register("unknown-1"),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnknownChainId("unknown-1".to_string()));
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            names.clone(),
+            &register("juno"),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NonCanonicalChainId("juno".to_string(), CHAIN_ID.to_string())
+    );
+
+    // Canonical chain ID succeeds.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        names.clone(),
+        &register(CHAIN_ID),
+        &[],
+    )
+    .unwrap();
+
+    // Removing the chain frees its aliases and blocks registrations.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        names.clone(),
+        &ExecuteMsg::RemoveChain {
+            chain_id: CHAIN_ID.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .wrap()
+        .query_wasm_smart::<GetChainResponse>(
+            names,
+            &QueryMsg::GetChain {
+                chain_id: "juno".to_string(),
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+}
+
+fn query_list_registrations_by_registrant(
+    app: &mut App,
+    contract_addr: Addr,
+    address: &str,
+    start_after: Option<(String, u64)>,
+    limit: Option<u32>,
+) -> Vec<(String, u64)> {
+    let res: ListRegistrationsByRegistrantResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::ListRegistrationsByRegistrant {
+                address: address.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+    res.registrations
+        .into_iter()
+        .map(|entry| {
+            assert_eq!(entry.chain_id, CHAIN_ID);
+            assert_eq!(entry.registration.registered_by, address);
+            (entry.name, entry.registration.code_id)
+        })
+        .collect()
+}
+
+#[test]
+fn test_list_registrations_by_registrant() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+
+    set_owner(
+        &mut app,
+        contract.clone(),
+        "cw-plus".to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    for (name, version, code_id, sender) in [
+        ("cw-plus", "0.0.1", 1, USER_ADDR),
+        ("cw-plus", "0.0.2", 3, USER_ADDR),
+        ("cw-plus", "0.0.3", 4, ADMIN_ADDR),
+        ("cw-core", "0.0.1", 2, ADMIN_ADDR),
+    ] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(sender),
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        query_list_registrations_by_registrant(&mut app, contract.clone(), USER_ADDR, None, None),
+        vec![("cw-plus".to_string(), 1), ("cw-plus".to_string(), 3)]
+    );
+    assert_eq!(
+        query_list_registrations_by_registrant(&mut app, contract.clone(), ADMIN_ADDR, None, None),
+        vec![("cw-core".to_string(), 2), ("cw-plus".to_string(), 4)]
+    );
+    assert_eq!(
+        query_list_registrations_by_registrant(
+            &mut app,
+            contract.clone(),
+            USER_ADDR,
+            Some((CHAIN_ID.to_string(), 1)),
+            Some(1)
+        ),
+        vec![("cw-plus".to_string(), 3)]
+    );
+
+    // Renames and unregistrations keep the index up to date.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Rename {
+            name: "cw-plus".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            new_name: "cw-minus".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    unregister(
+        &mut app,
+        contract.clone(),
+        "cw-minus".to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    assert_eq!(
+        query_list_registrations_by_registrant(&mut app, contract.clone(), USER_ADDR, None, None),
+        vec![("cw-minus".to_string(), 3)]
+    );
+    assert_eq!(
+        query_list_registrations_by_registrant(&mut app, contract, OTHER_USER_ADDR, None, None),
+        vec![]
+    );
+}
+
+#[test]
+fn test_resolve_many() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+
+    for (name, version, code_id) in [("cw-plus", "0.0.1", 1), ("cw-plus", "0.0.2", 2)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+        )
+        .unwrap();
+    }
+
+    let request = |name: &str, version: Option<&str>| ResolveRequest {
+        name: name.to_string(),
+        chain_id: CHAIN_ID.to_string(),
+        version: version.map(|v| v.to_string()),
+        include_prerelease: None,
+    };
+
+    let res: ResolveManyResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::ResolveMany {
+                requests: vec![
+                    request("cw-plus", None),
+                    request("cw-core", None),
+                    request("cw-plus", Some("0.0.1")),
+                    request("cw-plus", Some("0.0.3")),
+                ],
+            },
+        )
+        .unwrap();
+    let code_ids = res
+        .results
+        .iter()
+        .map(|result| {
+            result
+                .registration
+                .as_ref()
+                .map(|res| res.registration.code_id)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(code_ids, vec![Some(2), None, Some(1), None]);
+    for result in [&res.results[1], &res.results[3]] {
+        assert!(result
+            .error
+            .as_ref()
+            .unwrap()
+            .contains(&ContractError::NotFound {}.to_string()));
+    }
+
+    // Batches larger than the maximum are rejected.
+    let err = app
+        .wrap()
+        .query_wasm_smart::<ResolveManyResponse>(
+            contract,
+            &QueryMsg::ResolveMany {
+                requests: vec![request("cw-plus", None); MAX_RESOLVE_BATCH_SIZE + 1],
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::BatchTooLarge(MAX_RESOLVE_BATCH_SIZE).to_string()));
+}
+
+#[test]
+fn test_identify_contract() {
+    let mut app = setup_app();
+    app.update_block(|block| block.chain_id = CHAIN_ID.to_string());
+    // cw20-base is stored with code ID 1 and the registry with code ID 2.
+    let token = create_token(&mut app);
+    let contract = setup_test_case(&mut app, vec![]);
+
+    let identify = |app: &App, address: &Addr| {
+        app.wrap().query_wasm_smart::<IdentifyContractResponse>(
+            contract.clone(),
+            &QueryMsg::IdentifyContract {
+                address: address.to_string(),
+            },
+        )
+    };
+
+    // Unregistered code IDs are not found.
+    let err = identify(&app, &token).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+
+    for (name, version, code_id) in [
+        ("cw20-base", "v0.13.4", 1),
+        ("cw-code-id-registry", "0.0.1", 2),
+    ] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+        )
+        .unwrap();
+    }
+
+    let res = identify(&app, &token).unwrap();
+    assert_eq!(res.code_id, 1);
+    assert_eq!(res.name, "cw20-base");
+    // cw2 stores 0.13.4, which is the registered v0.13.4.
+    assert_eq!(res.registration.version, "v0.13.4");
+    assert_eq!(
+        res.contract_version.unwrap().contract,
+        "crates.io:cw20-base"
+    );
+    assert!(!res.name_mismatch);
+    assert!(!res.version_mismatch);
+
+    // The registry reports its real version, which differs from the
+    // registered one.
+    let res = identify(&app, &contract).unwrap();
+    assert_eq!(res.name, "cw-code-id-registry");
+    assert!(!res.name_mismatch);
+    assert!(res.version_mismatch);
+}
+
+fn declare_migration(
+    app: &mut App,
+    contract_addr: Addr,
+    from_version: &str,
+    to_version: &str,
+    sender: &str,
+) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::DeclareMigration {
+        name: "cw-plus".to_string(),
+        chain_id: CHAIN_ID.to_string(),
+        from_version: from_version.to_string(),
+        to_version: to_version.to_string(),
+        migrate_msg_schema_hash: None,
+        notes: Some(format!("{} to {}", from_version, to_version)),
+    };
+    app.execute_contract(Addr::unchecked(sender), contract_addr, &msg, &[])
+}
+
+fn query_migration_path(
+    app: &mut App,
+    contract_addr: Addr,
+    from_code_id: u64,
+    to_version: Option<&str>,
+) -> StdResult<Vec<(u64, u64)>> {
+    let res: MigrationPathResponse = app.wrap().query_wasm_smart(
+        contract_addr,
+        &QueryMsg::MigrationPath {
+            chain_id: CHAIN_ID.to_string(),
+            from_code_id,
+            to_version: to_version.map(|v| v.to_string()),
+        },
+    )?;
+    Ok(res
+        .hops
+        .into_iter()
+        .map(|hop| (hop.from_code_id, hop.to_code_id))
+        .collect())
+}
+
+#[test]
+fn test_migration_path() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+
+    set_owner(
+        &mut app,
+        contract.clone(),
+        "cw-plus".to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    for (version, code_id) in [("0.0.1", 1), ("0.0.2", 2), ("0.0.3", 3)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            "cw-plus".to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(USER_ADDR),
+        )
+        .unwrap();
+    }
+
+    // Only admin or owner can declare migrations.
+    let err: ContractError = declare_migration(
+        &mut app,
+        contract.clone(),
+        "0.0.1",
+        "0.0.2",
+        OTHER_USER_ADDR,
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError =
+        declare_migration(&mut app, contract.clone(), "0.0.1", "0.0.1", USER_ADDR)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, ContractError::InvalidMigration {});
+    let err: ContractError =
+        declare_migration(&mut app, contract.clone(), "0.0.1", "0.0.9", USER_ADDR)
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+    assert_eq!(err, ContractError::NotFound {});
+
+    for (from, to, sender) in [
+        ("0.0.1", "0.0.2", USER_ADDR),
+        ("0.0.2", "0.0.3", USER_ADDR),
+        ("0.0.1", "0.0.3", ADMIN_ADDR),
+    ] {
+        declare_migration(&mut app, contract.clone(), from, to, sender).unwrap();
+    }
+
+    // Shortest path to latest is used.
+    assert_eq!(
+        query_migration_path(&mut app, contract.clone(), 1, None).unwrap(),
+        vec![(1, 3)]
+    );
+    assert_eq!(
+        query_migration_path(&mut app, contract.clone(), 1, Some("0.0.2")).unwrap(),
+        vec![(1, 2)]
+    );
+    assert_eq!(
+        query_migration_path(&mut app, contract.clone(), 3, None).unwrap(),
+        vec![]
+    );
+    let err = query_migration_path(&mut app, contract.clone(), 3, Some("0.0.1")).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NoMigrationPath {}.to_string()));
+
+    // Removing the direct migration routes through the intermediate version.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::RemoveMigration {
+            name: "cw-plus".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            from_version: "0.0.1".to_string(),
+            to_version: "0.0.3".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_migration_path(&mut app, contract.clone(), 1, None).unwrap(),
+        vec![(1, 2), (2, 3)]
+    );
+
+    // Unregistering a version removes its migrations.
+    unregister(
+        &mut app,
+        contract.clone(),
+        "cw-plus".to_string(),
+        2,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    let err = query_migration_path(&mut app, contract, 1, None).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NoMigrationPath {}.to_string()));
+}
+
+fn register_with_dependencies(
+    app: &mut App,
+    contract_addr: Addr,
+    version: &str,
+    code_id: u64,
+    dependencies: Vec<(&str, &str)>,
+) -> AnyResult<AppResponse> {
+    let msg = ExecuteMsg::Register {
+        name: "dao-core".to_string(),
+        version: version.to_string(),
+        chain_id: CHAIN_ID.to_string(),
+        code_id,
+        checksum: version.to_string(),
+        dependencies: Some(
+            dependencies
+                .into_iter()
+                .map(|(name, version_req)| Dependency {
+                    name: name.to_string(),
+                    version_req: version_req.to_string(),
+                })
+                .collect(),
+        ),
+    };
+    app.execute_contract(Addr::unchecked(ADMIN_ADDR), contract_addr, &msg, &[])
+}
+
+fn query_reverse_dependencies(
+    app: &mut App,
+    contract_addr: Addr,
+    name: &str,
+    version: Option<&str>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Vec<u64> {
+    let res: ReverseDependenciesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract_addr,
+            &QueryMsg::ReverseDependencies {
+                name: name.to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                version: version.map(|v| v.to_string()),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+    res.dependents
+        .into_iter()
+        .map(|dependent| {
+            assert_eq!(dependent.name, "dao-core");
+            dependent.registration.code_id
+        })
+        .collect()
+}
+
+#[test]
+fn test_dependencies() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+
+    for (version, code_id) in [("0.1.0", 1), ("0.2.0", 2), ("1.0.0-rc.1", 3)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            "cw-voting".to_string(),
+            version.to_string(),
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+        )
+        .unwrap();
+    }
+
+    // Requirements must be valid and satisfied on the same chain.
+    for (dependency, version_req, expected) in [
+        (
+            "cw-voting",
+            "one",
+            ContractError::InvalidVersionReq("one".to_string()),
+        ),
+        (
+            "cw-voting",
+            "^0.3",
+            ContractError::UnsatisfiedDependency("cw-voting".to_string(), "^0.3".to_string()),
+        ),
+        (
+            "cw-voting",
+            "^1.0.0",
+            ContractError::UnsatisfiedDependency("cw-voting".to_string(), "^1.0.0".to_string()),
+        ),
+        (
+            "cw-voting",
+            "^18446744073709551615",
+            ContractError::UnsatisfiedDependency(
+                "cw-voting".to_string(),
+                "^18446744073709551615".to_string(),
+            ),
+        ),
+        (
+            "cw-voting",
+            "1.x",
+            ContractError::UnsatisfiedDependency("cw-voting".to_string(), "1.x".to_string()),
+        ),
+        (
+            "cw-proposal",
+            "*",
+            ContractError::UnsatisfiedDependency("cw-proposal".to_string(), "*".to_string()),
+        ),
+        (
+            "cw-proposal",
+            ">=0.1",
+            ContractError::UnsatisfiedDependency("cw-proposal".to_string(), ">=0.1".to_string()),
+        ),
+    ] {
+        let err: ContractError = register_with_dependencies(
+            &mut app,
+            contract.clone(),
+            "0.1.0",
+            4,
+            vec![(dependency, version_req)],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+        assert_eq!(err, expected);
+    }
+
+    for (version, code_id, version_req) in [
+        ("0.1.0", 4, "^0.1"),
+        ("0.2.0", 5, ">=0.2, <0.3"),
+        ("0.3.0-rc.1", 6, "=1.0.0-rc.1"),
+    ] {
+        register_with_dependencies(
+            &mut app,
+            contract.clone(),
+            version,
+            code_id,
+            vec![("cw-voting", version_req)],
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        query_reverse_dependencies(&mut app, contract.clone(), "cw-voting", None, None, None),
+        vec![4, 5, 6]
+    );
+    assert_eq!(
+        query_reverse_dependencies(
+            &mut app,
+            contract.clone(),
+            "cw-voting",
+            Some("0.1.0"),
+            None,
+            None
+        ),
+        vec![4]
+    );
+    assert_eq!(
+        query_reverse_dependencies(
+            &mut app,
+            contract.clone(),
+            "cw-voting",
+            Some("0.2.5"),
+            None,
+            None
+        ),
+        vec![5]
+    );
+    assert_eq!(
+        query_reverse_dependencies(
+            &mut app,
+            contract.clone(),
+            "cw-voting",
+            None,
+            Some(4),
+            Some(1)
+        ),
+        vec![5]
+    );
+
+    // Renaming the dependency keeps its dependents, also found by alias.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Rename {
+            name: "cw-voting".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            new_name: "dao-voting".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_reverse_dependencies(&mut app, contract.clone(), "dao-voting", None, None, None),
+        vec![4, 5, 6]
+    );
+
+    // Unregistering a dependent removes it.
+    unregister(
+        &mut app,
+        contract.clone(),
+        "dao-core".to_string(),
+        4,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    assert_eq!(
+        query_reverse_dependencies(&mut app, contract, "cw-voting", None, None, None),
+        vec![5, 6]
+    );
+}
+
+/// Hook contract recording the names it is notified of, or always failing
+/// if instantiated with `true`.
+fn hook_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |deps: DepsMut,
+         _env: Env,
+         _info: MessageInfo,
+         msg: CodeIdRegistryHookExecuteMsg|
+         -> StdResult<Response> {
+            let CodeIdRegistryHookExecuteMsg::CodeIdRegistryHook(
+                CodeIdRegistryHookMsg::NewRegistration { name, .. },
+            ) = msg;
+            if deps.storage.get(b"broken").is_some() {
+                return Err(StdError::generic_err("broken hook"));
+            }
+            let mut names: Vec<String> = deps
+                .storage
+                .get(b"names")
+                .map(|names| from_slice(&names))
+                .transpose()?
+                .unwrap_or_default();
+            names.push(name);
+            deps.storage.set(b"names", &to_vec(&names)?);
+            Ok(Response::new())
+        },
+        |deps: DepsMut, _env: Env, _info: MessageInfo, broken: bool| -> StdResult<Response> {
+            if broken {
+                deps.storage.set(b"broken", b"true");
+            }
+            Ok(Response::new())
+        },
+        |deps: Deps, _env: Env, _msg: Empty| -> StdResult<Binary> {
+            Ok(Binary(
+                deps.storage.get(b"names").unwrap_or_else(|| b"[]".to_vec()),
+            ))
+        },
+    );
+    Box::new(contract)
+}
+
+#[test]
+fn test_hooks() {
+    let mut app = setup_app();
+    let contract = setup_test_case(&mut app, vec![]);
+    let hook_code_id = app.store_code(hook_contract());
+    let mut hooks = vec![];
+    for (label, broken) in [("global", false), ("package", false), ("broken", true)] {
+        hooks.push(
+            app.instantiate_contract(
+                hook_code_id,
+                Addr::unchecked(ADMIN_ADDR),
+                &broken,
+                &[],
+                label,
+                None,
+            )
+            .unwrap(),
+        );
+    }
+    let add_hook = |name: Option<&str>, addr: &Addr| ExecuteMsg::AddHook {
+        name: name.map(|name| name.to_string()),
+        chain_id: name.map(|_| CHAIN_ID.to_string()),
+        addr: addr.to_string(),
+    };
+
+    set_owner(
+        &mut app,
+        contract.clone(),
+        "cw-plus".to_string(),
+        Some(USER_ADDR.to_string()),
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    // Only admin can add global hooks, and owners their package's hooks.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &add_hook(None, &hooks[0]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OTHER_USER_ADDR),
+            contract.clone(),
+            &add_hook(Some("cw-plus"), &hooks[1]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // A name without a chain ID, or the reverse, doesn't fall back to
+    // global hooks.
+    for (name, chain_id) in [(Some("cw-plus"), None), (None, Some(CHAIN_ID))] {
+        for msg in [
+            ExecuteMsg::AddHook {
+                name: name.map(|name| name.to_string()),
+                chain_id: chain_id.map(|chain_id| chain_id.to_string()),
+                addr: hooks[0].to_string(),
+            },
+            ExecuteMsg::RemoveHook {
+                name: name.map(|name| name.to_string()),
+                chain_id: chain_id.map(|chain_id| chain_id.to_string()),
+                addr: hooks[0].to_string(),
+            },
+        ] {
+            let err: ContractError = app
+                .execute_contract(Addr::unchecked(ADMIN_ADDR), contract.clone(), &msg, &[])
+                .unwrap_err()
+                .downcast()
+                .unwrap();
+            assert_eq!(err, ContractError::IncompletePackage {});
+        }
+        let err = app
+            .wrap()
+            .query_wasm_smart::<ListHooksResponse>(
+                contract.clone(),
+                &QueryMsg::ListHooks {
+                    name: name.map(|name| name.to_string()),
+                    chain_id: chain_id.map(|chain_id| chain_id.to_string()),
+                },
+            )
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&ContractError::IncompletePackage {}.to_string()));
+    }
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &add_hook(None, &hooks[0]),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &add_hook(Some("cw-plus"), &hooks[1]),
+        &[],
+    )
+    .unwrap();
+    // Also subscribed globally, but only notified once.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &add_hook(Some("cw-plus"), &hooks[0]),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &add_hook(None, &hooks[2]),
+        &[],
+    )
+    .unwrap();
+
+    let res: ListHooksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::ListHooks {
+                name: Some("cw-plus".to_string()),
+                chain_id: Some(CHAIN_ID.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.hooks.len(), 2);
+
+    for (name, code_id) in [("cw-plus", 1), ("cw-core", 2)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            "0.0.1".to_string(),
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+        )
+        .unwrap();
+    }
+
+    let notified = |app: &App, hook: &Addr| -> Vec<String> {
+        app.wrap().query_wasm_smart(hook, &Empty {}).unwrap()
+    };
+    assert_eq!(notified(&app, &hooks[0]), vec!["cw-plus", "cw-core"]);
+    assert_eq!(notified(&app, &hooks[1]), vec!["cw-plus"]);
+    // The broken hook failed without blocking registrations.
+    assert!(notified(&app, &hooks[2]).is_empty());
+    query_get_registration(&mut app, contract.clone(), "cw-core".to_string(), None).unwrap();
+
+    // Hooks removed globally are only notified as package hooks.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::RemoveHook {
+            name: None,
+            chain_id: None,
+            addr: hooks[0].to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    for (name, code_id) in [("cw-core", 3), ("cw-plus", 4)] {
+        register_native(
+            &mut app,
+            contract.clone(),
+            vec![],
+            name.to_string(),
+            "0.0.2".to_string(),
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+        )
+        .unwrap();
+    }
+    assert_eq!(
+        notified(&app, &hooks[0]),
+        vec!["cw-plus", "cw-core", "cw-plus"]
+    );
+    assert_eq!(notified(&app, &hooks[1]), vec!["cw-plus", "cw-plus"]);
+
+    // Package hooks follow a rename, and can be managed through the old
+    // name.
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::Rename {
+            name: "cw-plus".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            new_name: "cw-plus-v2".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER_ADDR),
+        contract.clone(),
+        &ExecuteMsg::RemoveHook {
+            name: Some("cw-plus".to_string()),
+            chain_id: Some(CHAIN_ID.to_string()),
+            addr: hooks[0].to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        "cw-plus-v2".to_string(),
+        "0.0.3".to_string(),
+        5,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+    assert_eq!(
+        notified(&app, &hooks[1]),
+        vec!["cw-plus", "cw-plus", "cw-plus-v2"]
+    );
+    assert_eq!(
+        notified(&app, &hooks[0]),
+        vec!["cw-plus", "cw-core", "cw-plus"]
+    );
+    let res: ListHooksResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract,
+            &QueryMsg::ListHooks {
+                name: Some("cw-plus".to_string()),
+                chain_id: Some(CHAIN_ID.to_string()),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.hooks, vec![hooks[1].clone()]);
+}
+
+/// Mock API that humanizes public key hashes as hex, since `MockApi` can
+/// only humanize addresses it canonicalized.
+struct SignerApi(MockApi);
+
+impl Api for SignerApi {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.0.addr_validate(human)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        self.0.addr_canonicalize(human)
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        match canonical.len() {
+            20 => Ok(Addr::unchecked(
+                canonical
+                    .as_slice()
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>(),
+            )),
+            _ => self.0.addr_humanize(canonical),
+        }
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.0
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.0
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.0.debug(message)
+    }
+}
+
+/// Signs a registration, returning the message to relay and the signer's
+/// address as humanized by `SignerApi`.
+fn sign_registration(
+    key: &SigningKey,
+    registry: &Addr,
+    registry_chain_id: &str,
+    code_id: u64,
+    nonce: u64,
+) -> (ExecuteMsg, Addr) {
+    let registration = SignedRegistration {
+        name: "cw-plus".to_string(),
+        version: format!("0.0.{}", code_id),
+        chain_id: CHAIN_ID.to_string(),
+        code_id,
+        checksum: "checksum".to_string(),
+        dependencies: None,
+    };
+    let payload = RegisterSignedPayload {
+        registry: registry.to_string(),
+        registry_chain_id: registry_chain_id.to_string(),
+        nonce,
+        registration: registration.clone(),
+    };
+    let signature: Signature = key.sign(&to_vec(&payload).unwrap());
+    let pubkey = key.verifying_key().to_bytes();
+    let signer = SignerApi(MockApi::default())
+        .addr_humanize(&CanonicalAddr::from(
+            Ripemd160::digest(Sha256::digest(pubkey.as_slice())).as_slice(),
+        ))
+        .unwrap();
+
+    let msg = ExecuteMsg::RegisterSigned {
+        registration,
+        pubkey: Binary::from(pubkey.as_slice()),
+        signature: Binary::from(signature.as_ref()),
+        nonce,
+    };
+    (msg, signer)
+}
+
+#[test]
+fn test_register_signed() {
+    let mut app = AppBuilder::new()
+        .with_api(SignerApi(MockApi::default()))
+        .build(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(OTHER_USER_ADDR),
+                    coins(1000, "ujuno"),
+                )
+                .unwrap();
+        });
+    let code_id = app.store_code(registry_contract());
+    let contract = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN_ADDR),
+            &InstantiateMsg {
+                admin: ADMIN_ADDR.to_string(),
+                payment_info: vec![PaymentInfo::NativePayment {
+                    token_denom: "ujuno".to_string(),
+                    payment_amount: Uint128::new(50),
+                }],
+                fee_recipients: None,
+            },
+            &[],
+            "Code ID Registry",
+            None,
+        )
+        .unwrap();
+
+    let chain_id = app.block_info().chain_id;
+    let owner_key = SigningKey::from_bytes(&[1; 32]).unwrap();
+    let other_key = SigningKey::from_bytes(&[2; 32]).unwrap();
+    let (msg, owner) = sign_registration(&owner_key, &contract, &chain_id, 1, 0);
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::SetOwner {
+            name: "cw-plus".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            owner: Some(owner.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Relayer submits and pays for the owner's registration.
+    let relayer = Addr::unchecked(OTHER_USER_ADDR);
+    app.execute_contract(relayer.clone(), contract.clone(), &msg, &coins(50, "ujuno"))
+        .unwrap();
+    let res: GetRegistrationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::GetRegistration {
+                name: "cw-plus".to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                version: None,
+                include_prerelease: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.registration.registered_by, owner);
+    assert_eq!(
+        app.wrap().query_balance(&relayer, "ujuno").unwrap().amount,
+        Uint128::new(950)
+    );
+    let res: GetNonceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            contract.clone(),
+            &QueryMsg::GetNonce {
+                address: owner.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.nonce, 1);
+
+    // Signatures cannot be replayed.
+    let err: ContractError = app
+        .execute_contract(relayer.clone(), contract.clone(), &msg, &coins(50, "ujuno"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidNonce { expected: 1 });
+
+    // Tampered registrations fail verification.
+    let (mut msg, _) = sign_registration(&owner_key, &contract, &chain_id, 2, 1);
+    if let ExecuteMsg::RegisterSigned {
+        ref mut registration,
+        ..
+    } = msg
+    {
+        registration.code_id = 3;
+    }
+    let err: ContractError = app
+        .execute_contract(relayer.clone(), contract.clone(), &msg, &coins(50, "ujuno"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSignature {});
+
+    // Signatures for a registry on another chain fail verification.
+    let (msg, _) = sign_registration(&owner_key, &contract, "other-chain", 2, 1);
+    let err: ContractError = app
+        .execute_contract(relayer.clone(), contract.clone(), &msg, &coins(50, "ujuno"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidSignature {});
+
+    // Only the owner's key can register.
+    let (msg, _) = sign_registration(&other_key, &contract, &chain_id, 2, 0);
+    let err: ContractError = app
+        .execute_contract(relayer.clone(), contract.clone(), &msg, &coins(50, "ujuno"))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // An exempt relayer still pays for a signer who is not exempt.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::UpdateFeeExemptions {
+            to_add: vec![relayer.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let (msg, _) = sign_registration(&owner_key, &contract, &chain_id, 2, 1);
+    let err: ContractError = app
+        .execute_contract(relayer.clone(), contract.clone(), &msg, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Payment(PaymentError::NoFunds {}));
+
+    // An exempt signer registers for free through any relayer.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        contract.clone(),
+        &ExecuteMsg::UpdateFeeExemptions {
+            to_add: vec![owner.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(Addr::unchecked(USER_ADDR), contract, &msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_instantiate_package() {
+    let mut app = setup_app();
+    app.update_block(|block| block.chain_id = CHAIN_ID.to_string());
+    // cw20-base is stored with code ID 1.
+    create_token(&mut app);
+    let contract = setup_test_case(&mut app, vec![]);
+    register_native(
+        &mut app,
+        contract.clone(),
+        vec![],
+        "cw20-base".to_string(),
+        "0.13.4".to_string(),
+        1,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap();
+
+    let instantiate = |name: &str, funds: Vec<Coin>| {
+        ExecuteMsg::InstantiatePackage(InstantiatePackageMsg {
+            name: name.to_string(),
+            version: None,
+            msg: to_binary(&cw20_base::msg::InstantiateMsg {
+                name: "Package Token".to_string(),
+                symbol: "PKG".to_string(),
+                decimals: 6,
+                initial_balances: vec![],
+                mint: None,
+                marketing: None,
+            })
+            .unwrap(),
+            label: "package token".to_string(),
+            admin: Some(USER_ADDR.to_string()),
+            funds,
+        })
+    };
+
+    // Funds must match those sent.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &instantiate("cw20-base", coins(10, "ujuno")),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::FundsMismatch {});
+
+    // Unknown packages are not found.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract.clone(),
+            &instantiate("cw-plus", vec![]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotFound {});
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            contract,
+            &instantiate("cw20-base", coins(10, "ujuno")),
+            &coins(10, "ujuno"),
+        )
+        .unwrap();
+    let res: InstantiatePackageResponse = from_binary(&res.data.unwrap()).unwrap();
+
+    let token_info: cw20::TokenInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &res.contract_address,
+            &cw20_base::msg::QueryMsg::TokenInfo {},
+        )
+        .unwrap();
+    assert_eq!(token_info.symbol, "PKG");
+    let contract_data = app
+        .contract_data(&Addr::unchecked(&res.contract_address))
+        .unwrap();
+    assert_eq!(contract_data.code_id, 1);
+    assert_eq!(contract_data.admin, Some(Addr::unchecked(USER_ADDR)));
+    assert_eq!(
+        app.wrap()
+            .query_balance(&res.contract_address, "ujuno")
+            .unwrap()
+            .amount,
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_helpers() {
+    let mut app = setup_app();
+    let token_addr = create_token(&mut app);
+    let contract_addr = setup_test_case(
+        &mut app,
+        vec![
+            PaymentInfo::Cw20Payment {
+                token_address: token_addr.to_string(),
+                payment_amount: Uint128::new(50),
+            },
+            PaymentInfo::NativePayment {
+                token_denom: "ujuno".to_string(),
+                payment_amount: Uint128::new(50),
+            },
+        ],
+    );
+    let registry = CodeIdRegistryContract(contract_addr.clone());
+    assert_eq!(registry.addr(), contract_addr);
+
+    let msg = registry
+        .set_owner("cw-name", CHAIN_ID, Some(USER_ADDR.to_string()))
+        .unwrap();
+    app.execute(Addr::unchecked(ADMIN_ADDR), msg).unwrap();
+
+    // Register paying with native tokens.
+    let msg = registry
+        .register("cw-name", "0.1.0", CHAIN_ID, 1, "one", coins(50, "ujuno"))
+        .unwrap();
+    app.execute(Addr::unchecked(USER_ADDR), msg).unwrap();
+
+    // Register paying with CW20 tokens.
+    let msg = registry
+        .call_cw20(
+            token_addr.clone(),
+            Uint128::new(50),
+            ReceiveMsg::Register {
+                name: "cw-name".to_string(),
+                version: "0.2.0".to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                code_id: 2,
+                checksum: "two".to_string(),
+                dependencies: None,
+            },
+        )
+        .unwrap();
+    app.execute(Addr::unchecked(USER_ADDR), msg).unwrap();
+    assert_eq!(
+        query_cw20_balance(&mut app, token_addr, Addr::unchecked(USER_ADDR)),
+        Uint128::new(950)
+    );
+
+    let querier = app.wrap();
+    assert_eq!(
+        registry
+            .latest_code_id(&querier, "cw-name", CHAIN_ID)
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        registry
+            .code_id_for_version(&querier, "cw-name", CHAIN_ID, "0.1.0")
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        registry.info_for_code_id(&querier, CHAIN_ID, 1).unwrap(),
+        InfoForCodeIdResponse {
+            registered_by: Addr::unchecked(USER_ADDR),
+            name: "cw-name".to_string(),
+            version: "0.1.0".to_string(),
+            checksum: "one".to_string(),
+            source: None,
+        }
+    );
+    registry
+        .latest_code_id(&querier, "unknown", CHAIN_ID)
+        .unwrap_err();
+
+    // Only the admin may unregister.
+    let msg = registry.unregister("cw-name", CHAIN_ID, 2).unwrap();
+    app.execute(Addr::unchecked(OTHER_USER_ADDR), msg.clone())
+        .unwrap_err();
+    app.execute(Addr::unchecked(ADMIN_ADDR), msg).unwrap();
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", CHAIN_ID)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_multitest_mock_registry() {
+    let mut app = App::default();
+    let admin = Addr::unchecked(ADMIN_ADDR);
+    let user = Addr::unchecked(USER_ADDR);
+    let token = mock_cw20(&mut app, &admin, &[(USER_ADDR, 100)]).unwrap();
+    fund_native(&mut app, &user, coins(100, "ujuno")).unwrap();
+
+    let registry = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_payment(native_payment("ujuno", 10))
+        .with_payment(cw20_payment(&token, 20))
+        .with_registration(MockRegistration::new("cw-name", "juno-1", "0.1.0", 1))
+        .with_registration(MockRegistration::new("cw-name", "juno-1", "0.2.0", 2))
+        .with_registration(MockRegistration::new("cw-name", "uni-3", "0.1.0", 7))
+        .with_owner("cw-name", "juno-1", USER_ADDR)
+        .build(&mut app)
+        .unwrap();
+
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "uni-3")
+            .unwrap(),
+        7
+    );
+    assert_eq!(
+        registry
+            .info_for_code_id(&app.wrap(), "juno-1", 1)
+            .unwrap()
+            .checksum,
+        format!("{:064x}", 1)
+    );
+
+    // Fees apply once seeded, in either payment mode.
+    register_paid(
+        &mut app,
+        &registry,
+        &user,
+        &native_payment("ujuno", 10),
+        MockRegistration::new("cw-name", "juno-1", "0.3.0", 3),
+    )
+    .unwrap();
+    register_paid(
+        &mut app,
+        &registry,
+        &user,
+        &cw20_payment(&token, 20),
+        MockRegistration::new("cw-name", "juno-1", "0.4.0", 4),
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_balance(USER_ADDR, "ujuno").unwrap().amount,
+        Uint128::new(90)
+    );
+    assert_eq!(
+        query_cw20_balance(&mut app, token, user.clone()),
+        Uint128::new(80)
+    );
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        4
+    );
+
+    // Only the seeded owner may register on juno-1.
+    fund_native(
+        &mut app,
+        &Addr::unchecked(OTHER_USER_ADDR),
+        coins(10, "ujuno"),
+    )
+    .unwrap();
+    register_paid(
+        &mut app,
+        &registry,
+        &Addr::unchecked(OTHER_USER_ADDR),
+        &native_payment("ujuno", 10),
+        MockRegistration::new("cw-name", "juno-1", "0.5.0", 5),
+    )
+    .unwrap_err();
+}
+
+fn export_all(app: &App, contract_addr: &Addr, limit: u32) -> Vec<ExportEntry> {
+    let mut entries = vec![];
+    let mut start_after = None;
+    loop {
+        let res: ExportResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::Export {
+                    start_after,
+                    limit: Some(limit),
+                },
+            )
+            .unwrap();
+        if res.entries.is_empty() {
+            return entries;
+        }
+        start_after = res.entries.last().map(ExportEntry::key);
+        entries.extend(res.entries);
+    }
+}
+
+#[test]
+fn test_export_import() {
+    let mut app = setup_app();
+    let old = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_registration(MockRegistration::new("cw-core", CHAIN_ID, "0.1.0", 1))
+        .with_registration(MockRegistration::new("cw-core", CHAIN_ID, "0.2.0", 2))
+        .with_registration(MockRegistration::new("cw-core", "uni-3", "0.1.0", 7))
+        .with_owner("cw-core", CHAIN_ID, USER_ADDR)
+        .with_owner("dao-core", CHAIN_ID, OTHER_USER_ADDR)
+        .with_registration(MockRegistration::new("cw-core", CHAIN_ID, "0.1.1", 8))
+        .build(&mut app)
+        .unwrap()
+        .addr();
+    for msg in [
+        ExecuteMsg::SetChain {
+            chain: Chain {
+                chain_id: CHAIN_ID.to_string(),
+                name: "Chain".to_string(),
+                bech32_prefix: "chain".to_string(),
+                network_type: NetworkType::Mainnet,
+                aliases: vec!["chain".to_string()],
+            },
+        },
+        ExecuteMsg::SetAlias {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            alias: "core".to_string(),
+        },
+        ExecuteMsg::SetImmutable { immutable: true },
+        ExecuteMsg::Unregister {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            code_id: 8,
+            reason: None,
+        },
+        ExecuteMsg::SetTag {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            tag: "stable".to_string(),
+            version: "0.1.0".to_string(),
+        },
+        ExecuteMsg::DeclareMigration {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            from_version: "0.1.0".to_string(),
+            to_version: "0.2.0".to_string(),
+            migrate_msg_schema_hash: None,
+            notes: None,
+        },
+        ExecuteMsg::UpdateFeeExemptions {
+            to_add: vec!["exempt".to_string()],
+            to_remove: vec![],
+        },
+        ExecuteMsg::SetNameFee {
+            name: "cw-other".to_string(),
+            payment_info: Some(vec![native_payment("ujuno", 10)]),
+        },
+        ExecuteMsg::SetChainIdFee {
+            chain_id: "uni-3".to_string(),
+            payment_info: Some(vec![]),
+        },
+        ExecuteMsg::AddHook {
+            name: None,
+            chain_id: None,
+            addr: "global_hook".to_string(),
+        },
+        ExecuteMsg::AddHook {
+            name: Some("cw-core".to_string()),
+            chain_id: Some(CHAIN_ID.to_string()),
+            addr: "hook".to_string(),
+        },
+        ExecuteMsg::SetRegistrationLimits {
+            rate_limit: None,
+            deposit: Some(native_payment("ujuno", 5)),
+        },
+        ExecuteMsg::SetUnregisterWindow {
+            window: Some(Duration::Height(100)),
+            refund: false,
+        },
+        ExecuteMsg::SetIbcConnection {
+            connection_id: "connection-0".to_string(),
+            chain_id: "uni-3".to_string(),
+        },
+    ] {
+        app.execute_contract(Addr::unchecked(ADMIN_ADDR), old.clone(), &msg, &[])
+            .unwrap();
+    }
+    // The dependency is declared by alias.
+    register_with_dependencies(&mut app, old.clone(), "1.0.0", 3, vec![("core", "^0.2")]).unwrap();
+    // The owner pays a deposit.
+    register_native(
+        &mut app,
+        old.clone(),
+        coins(5, "ujuno"),
+        "dao-core".to_string(),
+        "1.1.0".to_string(),
+        5,
+        Addr::unchecked(OTHER_USER_ADDR),
+    )
+    .unwrap();
+
+    // Entries come in import order, and pages pick up where they left off.
+    let entries = export_all(&app, &old, 4);
+    assert_eq!(entries.len(), 22);
+    assert!(matches!(entries[0], ExportEntry::Chain { .. }));
+    assert!(matches!(entries[1], ExportEntry::Alias { .. }));
+    assert!(matches!(entries[6], ExportEntry::Registration { .. }));
+    assert_eq!(
+        entries[7],
+        ExportEntry::Tombstone {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            tombstone: Tombstone {
+                version: "0.1.1".to_string(),
+                code_id: 8,
+                removed_by: Addr::unchecked(ADMIN_ADDR),
+                reason: None,
+            },
+        }
+    );
+    assert_eq!(
+        entries[8],
+        ExportEntry::Owner {
+            name: "cw-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            owner: Addr::unchecked(USER_ADDR),
+        }
+    );
+    assert_eq!(
+        entries[12],
+        ExportEntry::Deposit {
+            name: "dao-core".to_string(),
+            chain_id: CHAIN_ID.to_string(),
+            code_id: 5,
+            deposit: Deposit {
+                depositor: Addr::unchecked(OTHER_USER_ADDR),
+                amount: native_payment("ujuno", 5),
+                returned: false,
+            },
+        }
+    );
+    assert!(matches!(
+        entries[13],
+        ExportEntry::GracePeriod { code_id: 3, .. }
+    ));
+    assert!(matches!(
+        entries[14],
+        ExportEntry::GracePeriod { code_id: 5, .. }
+    ));
+    assert!(matches!(entries[19], ExportEntry::Hook { .. }));
+    assert_eq!(
+        entries[20],
+        ExportEntry::IbcConnection {
+            connection_id: "connection-0".to_string(),
+            chain_id: "uni-3".to_string(),
+        }
+    );
+    assert_eq!(
+        entries[21],
+        ExportEntry::Config {
+            config: query_config(&mut app, old.clone()),
+        }
+    );
+    assert_eq!(export_all(&app, &old, 1), entries);
+
+    let new = MockRegistryBuilder::new(ADMIN_ADDR)
+        .build(&mut app)
+        .unwrap()
+        .addr();
+
+    // Only the admin may import.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            new.clone(),
+            &ExecuteMsg::Import {
+                entries: entries.clone(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    for chunk in entries.chunks(4) {
+        app.execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            new.clone(),
+            &ExecuteMsg::Import {
+                entries: chunk.to_vec(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+    assert_eq!(export_all(&app, &new, 30), entries);
+
+    // Importing the same registrations again conflicts.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            new.clone(),
+            &ExecuteMsg::Import {
+                entries: entries[2..3].to_vec(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::CodeIDAlreadyRegistered(..)));
+
+    // Indexes were kept consistent.
+    let info: InfoForCodeIdResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &new,
+            &QueryMsg::InfoForCodeId {
+                chain_id: "uni-3".to_string(),
+                code_id: 7,
+            },
+        )
+        .unwrap();
+    assert_eq!(info.name, "cw-core");
+    assert_eq!(
+        query_reverse_dependencies(&mut app, new.clone(), "cw-core", Some("0.2.0"), None, None),
+        vec![3]
+    );
+    let res: GetRegistrationResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &new,
+            &QueryMsg::GetRegistration {
+                name: "cw-core".to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                version: None,
+                include_prerelease: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.registration.code_id, 2);
+
+    // Removed versions stay removed.
+    let err: ContractError = register_native(
+        &mut app,
+        new.clone(),
+        vec![],
+        "cw-core".to_string(),
+        "0.1.1".to_string(),
+        9,
+        Addr::unchecked(ADMIN_ADDR),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VersionRemoved(
+            "0.1.1".to_string(),
+            "cw-core".to_string(),
+            CHAIN_ID.to_string()
+        )
+    );
+
+    // Imported owners can register, paying the imported deposit.
+    register_native(
+        &mut app,
+        new.clone(),
+        coins(5, "ujuno"),
+        "cw-core".to_string(),
+        "0.3.0".to_string(),
+        4,
+        Addr::unchecked(USER_ADDR),
+    )
+    .unwrap();
+
+    // Imported entries are validated like the messages that set them.
+    let import = |app: &mut App, entries: Vec<ExportEntry>| -> ContractError {
+        app.execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            new.clone(),
+            &ExecuteMsg::Import { entries },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+    let chain = |chain_id: &str, aliases: Vec<&str>| ExportEntry::Chain {
+        chain: Chain {
+            chain_id: chain_id.to_string(),
+            name: "Chain".to_string(),
+            bech32_prefix: "chain".to_string(),
+            network_type: NetworkType::Mainnet,
+            aliases: aliases.into_iter().map(str::to_string).collect(),
+        },
+    };
+    let alias = |alias: &str, name: &str| ExportEntry::Alias {
+        alias: alias.to_string(),
+        chain_id: CHAIN_ID.to_string(),
+        name: name.to_string(),
+    };
+    assert_eq!(
+        import(&mut app, vec![chain("uni-3", vec!["uni-3"])]),
+        ContractError::InvalidChain {}
+    );
+    assert_eq!(
+        import(&mut app, vec![chain("uni-3", vec!["chain"])]),
+        ContractError::ChainIdInUse("chain".to_string())
+    );
+    assert_eq!(
+        import(
+            &mut app,
+            vec![ExportEntry::Tag {
+                name: "cw-core".to_string(),
+                chain_id: CHAIN_ID.to_string(),
+                tag: "beta".to_string(),
+                version: "9.9.9".to_string(),
+            }]
+        ),
+        ContractError::NotFound {}
+    );
+    assert_eq!(
+        import(&mut app, vec![alias("cw-core", "dao-core")]),
+        ContractError::NameInUse("cw-core".to_string())
+    );
+    assert_eq!(
+        import(&mut app, vec![alias("a", "b"), alias("b", "a")]),
+        ContractError::NameIsAlias("a".to_string())
+    );
+    let mut config = query_config(&mut app, new.clone());
+    config.upstreams = vec![new.clone()];
+    assert_eq!(
+        import(&mut app, vec![ExportEntry::Config { config }]),
+        ContractError::InvalidUpstream(new.to_string())
+    );
+
+    // Importing a chain again replaces its aliases.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        new.clone(),
+        &ExecuteMsg::Import {
+            entries: vec![chain(CHAIN_ID, vec!["renamed"])],
+        },
+        &[],
+    )
+    .unwrap();
+    app.wrap()
+        .query_wasm_smart::<GetChainResponse>(
+            &new,
+            &QueryMsg::GetChain {
+                chain_id: "chain".to_string(),
+            },
+        )
+        .unwrap_err();
+
+    // Once sealed, imports are rejected.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            new.clone(),
+            &ExecuteMsg::SealImport {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        new.clone(),
+        &ExecuteMsg::SealImport {},
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            new,
+            &ExecuteMsg::Import { entries: vec![] },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ImportSealed {});
+}
+
+#[test]
+fn test_upstreams() {
+    let mut app = setup_app();
+    let public = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_registration(MockRegistration::new("cw-core", CHAIN_ID, "0.1.0", 1))
+        .with_registration(MockRegistration::new("cw-core", CHAIN_ID, "0.2.0", 2))
+        .with_registration(MockRegistration::new("dao-core", CHAIN_ID, "0.1.0", 3))
+        .build(&mut app)
+        .unwrap();
+    let private = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_registration(MockRegistration::new("team-core", CHAIN_ID, "0.1.0", 10))
+        .with_registration(MockRegistration::new("dao-core", CHAIN_ID, "0.1.0", 11))
+        .build(&mut app)
+        .unwrap();
+
+    // Only the admin may set upstreams, which must not include the registry
+    // itself.
+    let set_upstreams = |upstreams: Vec<&Addr>| ExecuteMsg::SetUpstreams {
+        upstreams: upstreams.into_iter().map(Addr::to_string).collect(),
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            private.addr(),
+            &set_upstreams(vec![&public.0]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADMIN_ADDR),
+            private.addr(),
+            &set_upstreams(vec![&public.0, &private.0]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidUpstream(private.0.to_string()));
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        private.addr(),
+        &set_upstreams(vec![&public.0]),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_config(&mut app, private.addr()).upstreams,
+        vec![public.addr()]
+    );
+
+    // Names not registered locally are answered by the upstream.
+    let res = private
+        .registration(&app.wrap(), "cw-core", CHAIN_ID, None)
+        .unwrap();
+    assert_eq!(res.registration.code_id, 2);
+    assert_eq!(res.source, Some(public.addr()));
+    let res = private
+        .registration(&app.wrap(), "team-core", CHAIN_ID, None)
+        .unwrap();
+    assert_eq!(res.registration.code_id, 10);
+    assert_eq!(res.source, None);
+
+    // Local names shadow upstream ones.
+    let res = private
+        .registration(&app.wrap(), "dao-core", CHAIN_ID, None)
+        .unwrap();
+    assert_eq!(res.registration.code_id, 11);
+    assert_eq!(res.source, None);
+    private
+        .registration(&app.wrap(), "cw-core", "other-chain", None)
+        .unwrap_err();
+
+    let info = private.info_for_code_id(&app.wrap(), CHAIN_ID, 1).unwrap();
+    assert_eq!(info.name, "cw-core");
+    assert_eq!(info.source, Some(public.addr()));
+    assert_eq!(
+        private
+            .info_for_code_id(&app.wrap(), CHAIN_ID, 10)
+            .unwrap()
+            .source,
+        None
+    );
+    private
+        .info_for_code_id(&app.wrap(), CHAIN_ID, 99)
+        .unwrap_err();
+
+    // The registry that answered is reported through chained upstreams and
+    // batch resolution.
+    let leaf = MockRegistryBuilder::new(ADMIN_ADDR)
+        .build(&mut app)
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        leaf.addr(),
+        &set_upstreams(vec![&private.0]),
+        &[],
+    )
+    .unwrap();
+    let res: ResolveManyResponse = app
+        .wrap()
+        .query_wasm_smart(
+            leaf.addr(),
+            &QueryMsg::ResolveMany {
+                requests: vec![
+                    ResolveRequest {
+                        name: "cw-core".to_string(),
+                        chain_id: CHAIN_ID.to_string(),
+                        version: Some("0.1.0".to_string()),
+                        include_prerelease: None,
+                    },
+                    ResolveRequest {
+                        name: "team-core".to_string(),
+                        chain_id: CHAIN_ID.to_string(),
+                        version: None,
+                        include_prerelease: None,
+                    },
+                ],
+            },
+        )
+        .unwrap();
+    let sources: Vec<Option<Addr>> = res
+        .results
+        .into_iter()
+        .map(|result| result.registration.unwrap().source)
+        .collect();
+    assert_eq!(sources, vec![Some(public.addr()), Some(private.addr())]);
+}
+
+fn ibc_register_packet(chain_id: &str, code_id: u64) -> RegistryPacket {
+    RegistryPacket::Register {
+        name: "cw-core".to_string(),
+        chain_id: chain_id.to_string(),
+        registration: Registration {
+            registered_by: Addr::unchecked("juno1registrant"),
+            version: format!("0.{}.0", code_id),
+            code_id,
+            checksum: "checksum".to_string(),
+            prerelease: false,
+            dependencies: vec![],
+            yanked: false,
+        },
+    }
+}
+
+fn receive_packet(deps: DepsMut, channel_id: &str, packet: &RegistryPacket) -> RegistryAck {
+    let msg = mock_ibc_packet_recv(channel_id, packet).unwrap();
+    let res = ibc_packet_receive(deps, mock_env(), msg).unwrap();
+    from_binary(&res.acknowledgement).unwrap()
+}
+
+fn sent_packets(res: &Response) -> Vec<(String, RegistryPacket)> {
+    res.messages
+        .iter()
+        .filter_map(|msg| match &msg.msg {
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id, data, ..
+            }) => Some((channel_id.clone(), from_binary(data).unwrap())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_ibc_sync() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    crate::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        InstantiateMsg {
+            admin: ADMIN_ADDR.to_string(),
+            payment_info: vec![],
+            fee_recipients: None,
+        },
+    )
+    .unwrap();
+
+    // Channels may only be opened over trusted connections.
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_open_try("channel-0", IBC_ORDERING, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UntrustedConnection("connection-2".to_string())
+    );
+    let set_connection = ExecuteMsg::SetIbcConnection {
+        connection_id: "connection-2".to_string(),
+        chain_id: "juno-1".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER_ADDR, &[]),
+        set_connection.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        set_connection,
+    )
+    .unwrap();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_open_try("channel-0", IbcOrder::Ordered, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidIbcChannel(IBC_VERSION.to_string())
+    );
+    ibc_channel_open(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_open_try("channel-0", IBC_ORDERING, IBC_VERSION),
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_connect_ack("channel-0", IBC_ORDERING, IBC_VERSION),
+    )
+    .unwrap();
+    let res: ListIbcChannelsResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::ListIbcChannels {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        res.channels,
+        vec![IbcChannelEntry {
+            channel_id: "channel-0".to_string(),
+            connection_id: "connection-2".to_string(),
+            chain_id: Some("juno-1".to_string()),
+        }]
+    );
+
+    // Registrations and ownership changes for this chain are sent to
+    // connected registries. Those for other chains are not.
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::Register {
+            name: "cw-local".to_string(),
+            version: "0.1.0".to_string(),
+            chain_id: env.block.chain_id.clone(),
+            code_id: 1,
+            checksum: "checksum".to_string(),
+            dependencies: None,
+        },
+    )
+    .unwrap();
+    let packets = sent_packets(&res);
+    assert_eq!(packets.len(), 1);
+    assert_eq!(packets[0].0, "channel-0");
+    assert!(matches!(
+        &packets[0].1,
+        RegistryPacket::Register { name, .. } if name == "cw-local"
+    ));
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::Register {
+            name: "cw-local".to_string(),
+            version: "0.1.0".to_string(),
+            chain_id: "other-chain".to_string(),
+            code_id: 1,
+            checksum: "checksum".to_string(),
+            dependencies: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(sent_packets(&res), vec![]);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::SetOwner {
+            name: "cw-local".to_string(),
+            chain_id: env.block.chain_id.clone(),
+            owner: Some(USER_ADDR.to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        sent_packets(&res),
+        vec![(
+            "channel-0".to_string(),
+            RegistryPacket::SetOwner {
+                name: "cw-local".to_string(),
+                chain_id: env.block.chain_id.clone(),
+                owner: Some(Addr::unchecked(USER_ADDR)),
+            }
+        )]
+    );
+
+    // Changes are accepted from the chain they are for, and may be relayed
+    // more than once.
+    let packet = ibc_register_packet("juno-1", 5);
+    assert!(matches!(
+        receive_packet(deps.as_mut(), "channel-0", &packet),
+        RegistryAck::Result(_)
+    ));
+    assert!(matches!(
+        receive_packet(deps.as_mut(), "channel-0", &packet),
+        RegistryAck::Result(_)
+    ));
+    let res: InfoForCodeIdResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::InfoForCodeId {
+                chain_id: "juno-1".to_string(),
+                code_id: 5,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.registered_by, Addr::unchecked("juno1registrant"));
+    let owner_packet = RegistryPacket::SetOwner {
+        name: "cw-core".to_string(),
+        chain_id: "juno-1".to_string(),
+        owner: Some(Addr::unchecked("juno1owner")),
+    };
+    assert!(matches!(
+        receive_packet(deps.as_mut(), "channel-0", &owner_packet),
+        RegistryAck::Result(_)
+    ));
+    let res: ExportResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Export {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(res.entries.contains(&ExportEntry::Owner {
+        name: "cw-core".to_string(),
+        chain_id: "juno-1".to_string(),
+        owner: Addr::unchecked("juno1owner"),
+    }));
+
+    // Changes for other chains, and packets on unknown channels, are
+    // rejected.
+    assert_eq!(
+        receive_packet(
+            deps.as_mut(),
+            "channel-0",
+            &ibc_register_packet("osmosis-1", 6)
+        ),
+        RegistryAck::Error(
+            ContractError::UntrustedPacketSource("osmosis-1".to_string()).to_string()
+        )
+    );
+    assert_eq!(
+        receive_packet(
+            deps.as_mut(),
+            "channel-9",
+            &ibc_register_packet("juno-1", 6)
+        ),
+        RegistryAck::Error(ContractError::UnknownChannel("channel-9".to_string()).to_string())
+    );
+    query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::InfoForCodeId {
+            chain_id: "osmosis-1".to_string(),
+            code_id: 6,
+        },
+    )
+    .unwrap_err();
+
+    // While paused, packets are not acknowledged so they can be relayed
+    // again once unpaused.
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::Pause { until: None },
+    )
+    .unwrap();
+    let err = ibc_packet_receive(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_packet_recv("channel-0", &ibc_register_packet("juno-1", 7)).unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::Unpause {},
+    )
+    .unwrap();
+
+    // Packets that time out are resent with the current state, so a stale
+    // change can't overwrite a later one.
+    let stale_packet = RegistryPacket::SetOwner {
+        name: "cw-local".to_string(),
+        chain_id: env.block.chain_id.clone(),
+        owner: None,
+    };
+    let res = ibc_packet_timeout(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_packet_timeout("channel-0", &stale_packet).unwrap(),
+    )
+    .unwrap();
     assert_eq!(
-        config,
-        Config {
-            admin: Addr::unchecked(ADMIN_ADDR),
-            payment_info: PaymentInfo::Cw20Payment {
-                token_address: token.to_string(),
-                payment_amount: Uint128::new(50)
-            }
+        res.messages.len(),
+        1,
+        "expected the timed out packet to be resent"
+    );
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id, data, ..
+        }) => {
+            assert_eq!(channel_id, "channel-0");
+            assert_eq!(
+                from_binary::<RegistryPacket>(data).unwrap(),
+                RegistryPacket::SetOwner {
+                    name: "cw-local".to_string(),
+                    chain_id: env.block.chain_id.clone(),
+                    owner: Some(Addr::unchecked(USER_ADDR)),
+                }
+            );
         }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+
+    // Packets stop being trusted with their connection.
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN_ADDR, &[]),
+        ExecuteMsg::RemoveIbcConnection {
+            connection_id: "connection-2".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        receive_packet(
+            deps.as_mut(),
+            "channel-0",
+            &ibc_register_packet("juno-1", 6)
+        ),
+        RegistryAck::Error(
+            ContractError::UntrustedConnection("connection-2".to_string()).to_string()
+        )
     );
 
-    // Update config as non admin fails
-    let err: ContractError = update_config(
-        &mut app,
-        names.clone(),
-        Some(other_token.to_string()),
-        Some(PaymentInfo::NativePayment {
-            token_denom: "ujuno".to_string(),
-            payment_amount: Uint128::new(50),
-        }),
-        Addr::unchecked(OTHER_USER_ADDR),
+    // Acknowledgements are recorded.
+    let res = ibc_packet_ack(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_packet_ack(
+            "channel-0",
+            &packet,
+            IbcAcknowledgement::new(to_binary(&RegistryAck::Error("failed".to_string())).unwrap()),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&Attribute::new("success", "false")));
+
+    ibc_channel_close(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_channel_close_confirm("channel-0", IBC_ORDERING, IBC_VERSION),
     )
-    .unwrap_err()
-    .downcast()
     .unwrap();
+    let res: ListIbcChannelsResponse =
+        from_binary(&query(deps.as_ref(), env, QueryMsg::ListIbcChannels {}).unwrap()).unwrap();
+    assert_eq!(res.channels, vec![]);
+}
+
+#[test]
+fn test_unregister_window() {
+    let mut app = App::default();
+    let admin = Addr::unchecked(ADMIN_ADDR);
+    let user = Addr::unchecked(USER_ADDR);
+    fund_native(&mut app, &user, coins(100, "ujuno")).unwrap();
+
+    let registry = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_payment(native_payment("ujuno", 10))
+        .with_owner("cw-name", "juno-1", USER_ADDR)
+        .build(&mut app)
+        .unwrap();
+    let register = |app: &mut App, version: &str, code_id: u64| {
+        register_paid(
+            app,
+            &registry,
+            &user,
+            &native_payment("ujuno", 10),
+            MockRegistration::new("cw-name", "juno-1", version, code_id),
+        )
+    };
+
+    // Without a window, only admin may unregister.
+    register(&mut app, "0.1.0", 1).unwrap();
+    let err: ContractError = app
+        .execute(
+            user.clone(),
+            registry.unregister("cw-name", "juno-1", 1).unwrap(),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(err, ContractError::Unauthorized {});
 
-    // Ensure config stayed the same.
-    let config = query_config(&mut app, names.clone());
+    // Only admin may set the window.
+    let set_window = ExecuteMsg::SetUnregisterWindow {
+        window: Some(Duration::Height(10)),
+        refund: true,
+    };
+    let err: ContractError = app
+        .execute_contract(user.clone(), registry.addr(), &set_window, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(admin.clone(), registry.addr(), &set_window, &[])
+        .unwrap();
+
+    // The fee is held during the window and refunded if the owner withdraws.
+    register(&mut app, "0.2.0", 2).unwrap();
     assert_eq!(
-        config,
-        Config {
-            admin: Addr::unchecked(ADMIN_ADDR),
-            payment_info: PaymentInfo::Cw20Payment {
-                token_address: token.to_string(),
-                payment_amount: Uint128::new(50)
-            }
-        }
+        app.wrap().query_balance(USER_ADDR, "ujuno").unwrap().amount,
+        Uint128::new(80)
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance(ADMIN_ADDR, "ujuno")
+            .unwrap()
+            .amount,
+        Uint128::new(10)
+    );
+    app.execute(
+        user.clone(),
+        registry.unregister("cw-name", "juno-1", 2).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_balance(USER_ADDR, "ujuno").unwrap().amount,
+        Uint128::new(90)
+    );
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        1
     );
 
-    // Update config as admin
-    update_config(
-        &mut app,
-        names.clone(),
-        Some(OTHER_USER_ADDR.to_string()),
-        Some(PaymentInfo::NativePayment {
-            token_denom: "ujuno".to_string(),
-            payment_amount: Uint128::new(25),
-        }),
-        Addr::unchecked(ADMIN_ADDR),
+    // The fee can't be released until the window expires, after which the
+    // owner may only yank.
+    register(&mut app, "0.2.0", 3).unwrap();
+    let release = ExecuteMsg::ReleaseFee {
+        name: "cw-name".to_string(),
+        chain_id: "juno-1".to_string(),
+        code_id: 3,
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OTHER_USER_ADDR),
+            registry.addr(),
+            &release,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnregisterWindowOpen {});
+    app.update_block(|block| block.height += 10);
+    let err: ContractError = app
+        .execute(
+            user.clone(),
+            registry.unregister("cw-name", "juno-1", 3).unwrap(),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnregisterWindowExpired {});
+    app.execute_contract(
+        Addr::unchecked(OTHER_USER_ADDR),
+        registry.addr(),
+        &release,
+        &[],
     )
     .unwrap();
+    assert_eq!(
+        app.wrap()
+            .query_balance(ADMIN_ADDR, "ujuno")
+            .unwrap()
+            .amount,
+        Uint128::new(20)
+    );
 
-    let config = query_config(&mut app, names.clone());
+    // Yanked versions are skipped when resolving the latest version but can
+    // still be requested explicitly.
+    let yank = ExecuteMsg::Yank {
+        name: "cw-name".to_string(),
+        chain_id: "juno-1".to_string(),
+        code_id: 3,
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OTHER_USER_ADDR),
+            registry.addr(),
+            &yank,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(user.clone(), registry.addr(), &yank, &[])
+        .unwrap();
     assert_eq!(
-        config,
-        Config {
-            admin: Addr::unchecked(OTHER_USER_ADDR),
-            payment_info: PaymentInfo::NativePayment {
-                token_denom: "ujuno".to_string(),
-                payment_amount: Uint128::new(25)
-            }
-        }
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        1
     );
+    let yanked = registry
+        .registration(&app.wrap(), "cw-name", "juno-1", Some("0.2.0".to_string()))
+        .unwrap()
+        .registration;
+    assert!(yanked.yanked);
+    assert_eq!(yanked.code_id, 3);
 
-    // Update one config value but not the others
+    app.execute_contract(
+        user,
+        registry.addr(),
+        &ExecuteMsg::Unyank {
+            name: "cw-name".to_string(),
+            chain_id: "juno-1".to_string(),
+            code_id: 3,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        3
+    );
+}
 
-    // Only admin
-    update_config(
-        &mut app,
-        names.clone(),
-        Some(ADMIN_ADDR.to_string()),
-        None,
-        Addr::unchecked(OTHER_USER_ADDR),
+#[test]
+fn test_tombstones() {
+    let mut app = App::default();
+    let admin = Addr::unchecked(ADMIN_ADDR);
+    let registry = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_registration(MockRegistration::new("cw-name", "juno-1", "0.1.0", 1))
+        .with_registration(MockRegistration::new("cw-name", "juno-1", "0.2.0", 2))
+        .build(&mut app)
+        .unwrap();
+
+    // Only admin may enable immutable mode.
+    let set_immutable = ExecuteMsg::SetImmutable { immutable: true };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(USER_ADDR),
+            registry.addr(),
+            &set_immutable,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    app.execute_contract(admin.clone(), registry.addr(), &set_immutable, &[])
+        .unwrap();
+
+    app.execute_contract(
+        admin.clone(),
+        registry.addr(),
+        &ExecuteMsg::Unregister {
+            name: "cw-name".to_string(),
+            chain_id: "juno-1".to_string(),
+            code_id: 2,
+            reason: Some("wrong code ID".to_string()),
+        },
+        &[],
     )
     .unwrap();
 
-    let config = query_config(&mut app, names.clone());
+    // The removed version is reported as removed rather than not found.
+    let err = registry
+        .registration(&app.wrap(), "cw-name", "juno-1", Some("0.2.0".to_string()))
+        .unwrap_err();
+    assert!(err.to_string().contains(
+        &ContractError::VersionRemoved(
+            "0.2.0".to_string(),
+            "cw-name".to_string(),
+            "juno-1".to_string()
+        )
+        .to_string()
+    ));
+    let err = registry
+        .registration(&app.wrap(), "cw-name", "juno-1", Some("0.3.0".to_string()))
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::NotFound {}.to_string()));
+    let err = registry
+        .info_for_code_id(&app.wrap(), "juno-1", 2)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::CodeIDRemoved(2, "juno-1".to_string()).to_string()));
     assert_eq!(
-        config,
-        Config {
-            admin: Addr::unchecked(ADMIN_ADDR), // Only this has changed
-            payment_info: PaymentInfo::NativePayment {
-                token_denom: "ujuno".to_string(),
-                payment_amount: Uint128::new(25)
-            }
+        registry
+            .latest_code_id(&app.wrap(), "cw-name", "juno-1")
+            .unwrap(),
+        1
+    );
+
+    let tombstone: Tombstone = app
+        .wrap()
+        .query_wasm_smart(
+            registry.addr(),
+            &QueryMsg::GetTombstone {
+                name: "cw-name".to_string(),
+                chain_id: "juno-1".to_string(),
+                version: "0.2.0".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        tombstone,
+        Tombstone {
+            version: "0.2.0".to_string(),
+            code_id: 2,
+            removed_by: admin.clone(),
+            reason: Some("wrong code ID".to_string()),
         }
     );
 
-    // Only payment info
-    update_config(
-        &mut app,
-        names.clone(),
-        None,
-        Some(PaymentInfo::NativePayment {
-            token_denom: "uatom".to_string(),
-            payment_amount: Uint128::new(50),
-        }),
-        Addr::unchecked(ADMIN_ADDR),
+    // Neither the version nor the code ID can be reused.
+    let err: ContractError = app
+        .execute(
+            admin.clone(),
+            registry
+                .register("cw-name", "0.2.0", "juno-1", 3, "checksum", vec![])
+                .unwrap(),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::VersionRemoved(
+            "0.2.0".to_string(),
+            "cw-name".to_string(),
+            "juno-1".to_string()
+        )
+    );
+    let err: ContractError = app
+        .execute(
+            admin,
+            registry
+                .register("cw-other", "0.1.0", "juno-1", 2, "checksum", vec![])
+                .unwrap(),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CodeIDRemoved(2, "juno-1".to_string()));
+}
+
+#[test]
+fn test_registration_limits() {
+    let mut app = App::default();
+    let admin = Addr::unchecked(ADMIN_ADDR);
+    let user = Addr::unchecked(USER_ADDR);
+    fund_native(&mut app, &user, coins(100, "ujuno")).unwrap();
+
+    let registry = MockRegistryBuilder::new(ADMIN_ADDR)
+        .with_owner("cw-name", "juno-1", USER_ADDR)
+        .build(&mut app)
+        .unwrap();
+    let register = |app: &mut App, version: &str, code_id: u64, amount: u128| {
+        app.execute(
+            user.clone(),
+            registry
+                .register(
+                    "cw-name",
+                    version,
+                    "juno-1",
+                    code_id,
+                    "checksum",
+                    coins(amount, "ujuno"),
+                )
+                .unwrap(),
+        )
+    };
+    let balance = |app: &App| {
+        app.wrap()
+            .query_balance(USER_ADDR, "ujuno")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    // Rate limits must allow registering.
+    let err: ContractError = app
+        .execute_contract(
+            admin.clone(),
+            registry.addr(),
+            &ExecuteMsg::SetRegistrationLimits {
+                rate_limit: Some(RateLimit {
+                    max_registrations: 0,
+                    window: Duration::Height(10),
+                }),
+                deposit: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidRateLimit {});
+    app.execute_contract(
+        admin.clone(),
+        registry.addr(),
+        &ExecuteMsg::SetRegistrationLimits {
+            rate_limit: Some(RateLimit {
+                max_registrations: 2,
+                window: Duration::Height(10),
+            }),
+            deposit: Some(native_payment("ujuno", 5)),
+        },
+        &[],
     )
     .unwrap();
 
-    let config = query_config(&mut app, names);
+    // A deposit is required, and anything over it is returned since
+    // registering is free.
+    let err: ContractError = register(&mut app, "0.1.0", 1, 4)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
     assert_eq!(
-        config,
-        Config {
-            admin: Addr::unchecked(ADMIN_ADDR),
-            payment_info: PaymentInfo::NativePayment {
-                token_denom: "uatom".to_string(),
-                payment_amount: Uint128::new(50)
-            }
-        }
+        err,
+        ContractError::DepositRequired(Uint128::new(5), "ujuno".to_string())
     );
+    register(&mut app, "0.1.0", 1, 5).unwrap();
+    register(&mut app, "0.2.0", 2, 7).unwrap();
+    assert_eq!(balance(&app), 90);
+
+    // Only two registrations are allowed per window.
+    let err: ContractError = register(&mut app, "0.3.0", 3, 5)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::RateLimited(_)));
+
+    // Deposits are returned on yank and unregister.
+    app.execute_contract(
+        user.clone(),
+        registry.addr(),
+        &ExecuteMsg::Yank {
+            name: "cw-name".to_string(),
+            chain_id: "juno-1".to_string(),
+            code_id: 1,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(balance(&app), 95);
+    app.execute(
+        admin.clone(),
+        registry.unregister("cw-name", "juno-1", 2).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(balance(&app), 100);
+
+    // Yanked versions are not listed, and can't be unyanked once their
+    // deposit has been returned.
+    let registrations: ListRegistrationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registry.addr(),
+            &QueryMsg::ListRegistrations {
+                name: "cw-name".to_string(),
+                chain_id: "juno-1".to_string(),
+                include_prerelease: None,
+            },
+        )
+        .unwrap();
+    assert!(registrations.registrations.is_empty());
+    let err: ContractError = app
+        .execute_contract(
+            user.clone(),
+            registry.addr(),
+            &ExecuteMsg::Unyank {
+                name: "cw-name".to_string(),
+                chain_id: "juno-1".to_string(),
+                code_id: 1,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::DepositReturned {});
+
+    // Nor is the deposit returned twice.
+    app.execute(admin, registry.unregister("cw-name", "juno-1", 1).unwrap())
+        .unwrap();
+    assert_eq!(balance(&app), 100);
+
+    // A new window starts once the last one expires.
+    app.update_block(|block| block.height += 10);
+    register(&mut app, "0.3.0", 3, 5).unwrap();
+    assert_eq!(balance(&app), 95);
+}
+
+#[test]
+fn test_receive_from_unrecognized_cw20() {
+    let mut app = App::default();
+    let token = mock_cw20(&mut app, &Addr::unchecked(ADMIN_ADDR), &[(USER_ADDR, 100)]).unwrap();
+    let registry = MockRegistryBuilder::new(ADMIN_ADDR)
+        .build(&mut app)
+        .unwrap();
+    let receive = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: ADMIN_ADDR.to_string(),
+        amount: Uint128::zero(),
+        msg: to_binary(&ReceiveMsg::Register {
+            name: "cw-name".to_string(),
+            version: "0.1.0".to_string(),
+            chain_id: "juno-1".to_string(),
+            code_id: 1,
+            checksum: "checksum".to_string(),
+            dependencies: None,
+        })
+        .unwrap(),
+    });
+
+    // Registering is free, so nothing but the token check stops anyone from
+    // registering as the admin by calling Receive directly.
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(USER_ADDR), registry.addr(), &receive, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnrecognizedCw20 {});
+
+    // The same holds once a different CW20 is accepted.
+    app.execute_contract(
+        Addr::unchecked(ADMIN_ADDR),
+        registry.addr(),
+        &ExecuteMsg::UpdateConfig {
+            admin: None,
+            payment_info: Some(vec![cw20_payment(&token, 10)]),
+            fee_recipients: None,
+            validate_chain_ids: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(USER_ADDR), registry.addr(), &receive, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::UnrecognizedCw20 {});
+    assert!(registry
+        .registration(&app.wrap(), "cw-name", "juno-1", None)
+        .is_err());
+}
+
+#[test]
+fn test_hook_gas_limit() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let admin = mock_info(ADMIN_ADDR, &[]);
+    crate::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        InstantiateMsg {
+            admin: ADMIN_ADDR.to_string(),
+            payment_info: vec![],
+            fee_recipients: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        ExecuteMsg::AddHook {
+            name: None,
+            chain_id: None,
+            addr: "hook".to_string(),
+        },
+    )
+    .unwrap();
+    let register = |deps: DepsMut, code_id: u64| {
+        execute(
+            deps,
+            env.clone(),
+            admin.clone(),
+            ExecuteMsg::Register {
+                name: "cw-name".to_string(),
+                version: format!("0.{}.0", code_id),
+                chain_id: CHAIN_ID.to_string(),
+                code_id,
+                checksum: "checksum".to_string(),
+                dependencies: None,
+            },
+        )
+        .unwrap()
+    };
+
+    // Hooks are always given a gas limit, so running out of gas is caught
+    // like any other failure.
+    let res = register(deps.as_mut(), 1);
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].gas_limit, Some(DEFAULT_HOOK_GAS_LIMIT));
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER_ADDR, &[]),
+        ExecuteMsg::SetHookGasLimit { gas_limit: 100_000 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        ExecuteMsg::SetHookGasLimit { gas_limit: 100_000 },
+    )
+    .unwrap();
+    let res = register(deps.as_mut(), 2);
+    assert_eq!(res.messages[0].gas_limit, Some(100_000));
+}
+
+#[test]
+fn test_version_parsing() {
+    use crate::version::{is_prerelease, parse_version, parse_version_req, same_version};
+
+    assert_eq!(parse_version("v1.2.3"), parse_version("1.2.3"));
+    assert_eq!(parse_version("1.2.3+build.5").unwrap().patch, 3);
+    assert!(parse_version("18446744073709551616.0.0").is_none());
+    assert!(parse_version("1.2").is_none());
+    assert!(parse_version("one").is_none());
+
+    let max = parse_version("18446744073709551615.0.0").unwrap();
+    for req in ["^18446744073709551615", "~18446744073709551615", "*"] {
+        assert!(parse_version_req(req).unwrap().matches(&max));
+    }
+
+    let req = parse_version_req("1.x").unwrap();
+    assert!(req.matches(&parse_version("1.9.0").unwrap()));
+    assert!(!req.matches(&parse_version("2.0.0").unwrap()));
+    assert!(!req.matches(&parse_version("1.1.0-rc.1").unwrap()));
+    assert!(parse_version_req("one").is_none());
+
+    assert!(is_prerelease("v1.1.0-rc.1"));
+    assert!(is_prerelease("1.1.0-rc.1+build-5"));
+    assert!(!is_prerelease("v1.0.1+build-5"));
+    assert!(!is_prerelease("not-semver"));
+
+    assert!(same_version("v0.13.4", "0.13.4"));
+    assert!(!same_version("v0.13.4", "0.13.5"));
+    assert!(same_version("main", "main"));
+    assert!(!same_version("main", "v0.13.4"));
 }
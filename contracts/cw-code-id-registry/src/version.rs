@@ -0,0 +1,27 @@
+pub use semver::{Version, VersionReq};
+
+/// Parses a semver version. A leading `v`, as in `v1.0.0`, is allowed.
+pub fn parse_version(version: &str) -> Option<Version> {
+    Version::parse(version.strip_prefix('v').unwrap_or(version)).ok()
+}
+
+/// Parses a version requirement such as `^1.2`, `>=0.1.0, <0.3`, `1.x` or
+/// `=1.0.0-rc.1`, with the same meaning as in Cargo.
+pub fn parse_version_req(req: &str) -> Option<VersionReq> {
+    VersionReq::parse(req).ok()
+}
+
+/// Whether a version is a semver pre-release, such as `v1.0.0-rc.1`.
+/// Versions that are not semver are never pre-releases.
+pub fn is_prerelease(version: &str) -> bool {
+    parse_version(version).is_some_and(|version| !version.pre.is_empty())
+}
+
+/// Whether two versions are the same, ignoring a leading `v`. Versions that
+/// are not semver must match exactly.
+pub fn same_version(a: &str, b: &str) -> bool {
+    match (parse_version(a), parse_version(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
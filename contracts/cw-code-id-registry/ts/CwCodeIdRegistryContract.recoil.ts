@@ -6,7 +6,7 @@
 
 import { selectorFamily } from "recoil";
 import { cosmWasmClient } from "./chain";
-import { Addr, PaymentInfo, Uint128, ConfigResponse, ExecuteMsg, Binary, Cw20ReceiveMsg, GetRegistrationResponse, Registration, InfoForCodeIdResponse, InstantiateMsg, ListRegistrationsResponse, QueryMsg, ReceiveMsg } from "./CwCodeIdRegistryContract";
+import { NetworkType, Chain, CodeIdRegistryHookExecuteMsg, CodeIdRegistryHookMsg, Addr, Registration, Dependency, PaymentInfo, Uint128, Decimal, Duration, ConfigResponse, RateLimit, ExecuteMsg, Binary, Expiration, Timestamp, Uint64, ExportEntry, Cw20ReceiveMsg, SignedRegistration, InstantiatePackageMsg, Coin, Tombstone, Migration, Deposit, GracePeriod, Config, ExportResponse, GetChainResponse, GetFeeResponse, GetNonceResponse, GetRegistrationResponse, GetTaggedRegistrationResponse, GetTombstoneResponse, IdentifyContractResponse, ContractVersion, InfoForCodeIdResponse, InstantiateMsg, InstantiatePackageResponse, ListChainsResponse, ListHooksResponse, ListIbcChannelsResponse, IbcChannelEntry, ListRegistrationsByRegistrantResponse, RegistrantEntry, ListRegistrationsResponse, ListTagsResponse, MigrationPathResponse, MigrationHop, PauseStatusResponse, QueryMsg, ExportKey, ResolveRequest, ReceiveMsg, RegisterSignedPayload, RegistryAck, RegistryPacket, ResolveManyResponse, ResolveResult, ReverseDependenciesResponse, ReverseDependency } from "./CwCodeIdRegistryContract";
 import { CwCodeIdRegistryQueryClient } from "./CwCodeIdRegistryContract.ts";
 type QueryClientParams = {
   contractAddress: string;
@@ -53,6 +53,21 @@ export const getRegistrationSelector = selectorFamily<GetRegistrationResponse |
     return await client.getRegistration(...params);
   }
 });
+export const resolveManySelector = selectorFamily<ResolveManyResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["resolveMany"]>;
+}>({
+  key: "cwCodeIdRegistryResolveMany",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.resolveMany(...params);
+  }
+});
 export const infoForCodeIdSelector = selectorFamily<InfoForCodeIdResponse | undefined, QueryClientParams & {
   params: Parameters<CwCodeIdRegistryQueryClient["infoForCodeId"]>;
 }>({
@@ -82,4 +97,229 @@ export const listRegistrationsSelector = selectorFamily<ListRegistrationsRespons
     if (!client) return;
     return await client.listRegistrations(...params);
   }
+});
+export const getFeeSelector = selectorFamily<GetFeeResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["getFee"]>;
+}>({
+  key: "cwCodeIdRegistryGetFee",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.getFee(...params);
+  }
+});
+export const pauseStatusSelector = selectorFamily<PauseStatusResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["pauseStatus"]>;
+}>({
+  key: "cwCodeIdRegistryPauseStatus",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.pauseStatus(...params);
+  }
+});
+export const getTaggedRegistrationSelector = selectorFamily<GetTaggedRegistrationResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["getTaggedRegistration"]>;
+}>({
+  key: "cwCodeIdRegistryGetTaggedRegistration",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.getTaggedRegistration(...params);
+  }
+});
+export const listTagsSelector = selectorFamily<ListTagsResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["listTags"]>;
+}>({
+  key: "cwCodeIdRegistryListTags",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.listTags(...params);
+  }
+});
+export const listRegistrationsByRegistrantSelector = selectorFamily<ListRegistrationsByRegistrantResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["listRegistrationsByRegistrant"]>;
+}>({
+  key: "cwCodeIdRegistryListRegistrationsByRegistrant",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.listRegistrationsByRegistrant(...params);
+  }
+});
+export const getNonceSelector = selectorFamily<GetNonceResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["getNonce"]>;
+}>({
+  key: "cwCodeIdRegistryGetNonce",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.getNonce(...params);
+  }
+});
+export const listHooksSelector = selectorFamily<ListHooksResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["listHooks"]>;
+}>({
+  key: "cwCodeIdRegistryListHooks",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.listHooks(...params);
+  }
+});
+export const reverseDependenciesSelector = selectorFamily<ReverseDependenciesResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["reverseDependencies"]>;
+}>({
+  key: "cwCodeIdRegistryReverseDependencies",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.reverseDependencies(...params);
+  }
+});
+export const migrationPathSelector = selectorFamily<MigrationPathResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["migrationPath"]>;
+}>({
+  key: "cwCodeIdRegistryMigrationPath",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.migrationPath(...params);
+  }
+});
+export const identifyContractSelector = selectorFamily<IdentifyContractResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["identifyContract"]>;
+}>({
+  key: "cwCodeIdRegistryIdentifyContract",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.identifyContract(...params);
+  }
+});
+export const getChainSelector = selectorFamily<GetChainResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["getChain"]>;
+}>({
+  key: "cwCodeIdRegistryGetChain",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.getChain(...params);
+  }
+});
+export const getTombstoneSelector = selectorFamily<GetTombstoneResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["getTombstone"]>;
+}>({
+  key: "cwCodeIdRegistryGetTombstone",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.getTombstone(...params);
+  }
+});
+export const listChainsSelector = selectorFamily<ListChainsResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["listChains"]>;
+}>({
+  key: "cwCodeIdRegistryListChains",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.listChains(...params);
+  }
+});
+export const exportSelector = selectorFamily<ExportResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["export"]>;
+}>({
+  key: "cwCodeIdRegistryExport",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.export(...params);
+  }
+});
+export const listIbcChannelsSelector = selectorFamily<ListIbcChannelsResponse | undefined, QueryClientParams & {
+  params: Parameters<CwCodeIdRegistryQueryClient["listIbcChannels"]>;
+}>({
+  key: "cwCodeIdRegistryListIbcChannels",
+  get: ({
+    params,
+    ...queryClientParams
+  }) => async ({
+    get
+  }) => {
+    const client = get(queryClient(queryClientParams));
+    if (!client) return;
+    return await client.listIbcChannels(...params);
+  }
 });
\ No newline at end of file
@@ -5,11 +5,41 @@
 */
 
 import { CosmWasmClient, ExecuteResult, SigningCosmWasmClient } from "@cosmjs/cosmwasm-stargate";
-import { Coin, StdFee } from "@cosmjs/amino";
+import { StdFee } from "@cosmjs/amino";
+export type NetworkType = "mainnet" | "testnet";
+export interface Chain {
+  aliases: string[];
+  bech32_prefix: string;
+  chain_id: string;
+  name: string;
+  network_type: NetworkType;
+}
+export type CodeIdRegistryHookExecuteMsg = {
+  code_id_registry_hook: CodeIdRegistryHookMsg;
+};
+export type CodeIdRegistryHookMsg = {
+  new_registration: {
+    chain_id: string;
+    name: string;
+    registration: Registration;
+    [k: string]: unknown;
+  };
+};
 export type Addr = string;
+export interface Registration {
+  checksum: string;
+  code_id: number;
+  dependencies?: Dependency[];
+  prerelease: boolean;
+  registered_by: Addr;
+  version: string;
+  yanked?: boolean;
+}
+export interface Dependency {
+  name: string;
+  version_req: string;
+}
 export type PaymentInfo = {
-  none: {};
-} | {
   native_payment: {
     payment_amount: Uint128;
     token_denom: string;
@@ -21,9 +51,28 @@ export type PaymentInfo = {
   };
 };
 export type Uint128 = string;
+export type Decimal = string;
+export type Duration = {
+  height: number;
+} | {
+  time: number;
+};
 export interface ConfigResponse {
   admin: Addr;
-  payment_info: PaymentInfo;
+  deposit?: PaymentInfo | null;
+  fee_recipients: [Addr, Decimal][];
+  hook_gas_limit?: number;
+  immutable?: boolean;
+  payment_info: PaymentInfo[];
+  rate_limit?: RateLimit | null;
+  refund_on_unregister?: boolean;
+  unregister_window?: Duration | null;
+  upstreams?: Addr[];
+  validate_chain_ids?: boolean;
+}
+export interface RateLimit {
+  max_registrations: number;
+  window: Duration;
 }
 export type ExecuteMsg = {
   receive: Cw20ReceiveMsg;
@@ -32,9 +81,19 @@ export type ExecuteMsg = {
     chain_id: string;
     checksum: string;
     code_id: number;
+    dependencies?: Dependency[] | null;
     name: string;
     version: string;
   };
+} | {
+  register_signed: {
+    nonce: number;
+    pubkey: Binary;
+    registration: SignedRegistration;
+    signature: Binary;
+  };
+} | {
+  instantiate_package: InstantiatePackageMsg;
 } | {
   set_owner: {
     chain_id: string;
@@ -46,50 +105,432 @@ export type ExecuteMsg = {
     chain_id: string;
     code_id: number;
     name: string;
+    reason?: string | null;
   };
 } | {
   update_config: {
     admin?: string | null;
-    payment_info?: PaymentInfo | null;
+    fee_recipients?: [string, Decimal][] | null;
+    payment_info?: PaymentInfo[] | null;
+    validate_chain_ids?: boolean | null;
+  };
+} | {
+  update_fee_exemptions: {
+    to_add: string[];
+    to_remove: string[];
+  };
+} | {
+  set_name_fee: {
+    name: string;
+    payment_info?: PaymentInfo[] | null;
+  };
+} | {
+  set_chain_id_fee: {
+    chain_id: string;
+    payment_info?: PaymentInfo[] | null;
+  };
+} | {
+  pause: {
+    until?: Expiration | null;
+  };
+} | {
+  unpause: {};
+} | {
+  set_tag: {
+    chain_id: string;
+    name: string;
+    tag: string;
+    version: string;
+  };
+} | {
+  remove_tag: {
+    chain_id: string;
+    name: string;
+    tag: string;
+  };
+} | {
+  set_alias: {
+    alias: string;
+    chain_id: string;
+    name: string;
+  };
+} | {
+  remove_alias: {
+    alias: string;
+    chain_id: string;
+  };
+} | {
+  rename: {
+    chain_id: string;
+    name: string;
+    new_name: string;
+  };
+} | {
+  declare_migration: {
+    chain_id: string;
+    from_version: string;
+    migrate_msg_schema_hash?: string | null;
+    name: string;
+    notes?: string | null;
+    to_version: string;
+  };
+} | {
+  remove_migration: {
+    chain_id: string;
+    from_version: string;
+    name: string;
+    to_version: string;
+  };
+} | {
+  add_hook: {
+    addr: string;
+    chain_id?: string | null;
+    name?: string | null;
+  };
+} | {
+  remove_hook: {
+    addr: string;
+    chain_id?: string | null;
+    name?: string | null;
+  };
+} | {
+  set_hook_gas_limit: {
+    gas_limit: number;
+  };
+} | {
+  set_chain: {
+    chain: Chain;
+  };
+} | {
+  remove_chain: {
+    chain_id: string;
+  };
+} | {
+  import: {
+    entries: ExportEntry[];
+  };
+} | {
+  seal_import: {};
+} | {
+  set_upstreams: {
+    upstreams: string[];
+  };
+} | {
+  set_ibc_connection: {
+    chain_id: string;
+    connection_id: string;
+  };
+} | {
+  remove_ibc_connection: {
+    connection_id: string;
+  };
+} | {
+  set_unregister_window: {
+    refund: boolean;
+    window?: Duration | null;
+  };
+} | {
+  set_immutable: {
+    immutable: boolean;
+  };
+} | {
+  set_registration_limits: {
+    deposit?: PaymentInfo | null;
+    rate_limit?: RateLimit | null;
+  };
+} | {
+  release_fee: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+  };
+} | {
+  yank: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+  };
+} | {
+  unyank: {
+    chain_id: string;
+    code_id: number;
+    name: string;
   };
 };
 export type Binary = string;
+export type Expiration = {
+  at_height: number;
+} | {
+  at_time: Timestamp;
+} | {
+  never: {
+    [k: string]: unknown;
+  };
+};
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type ExportEntry = {
+  chain: {
+    chain: Chain;
+  };
+} | {
+  alias: {
+    alias: string;
+    chain_id: string;
+    name: string;
+  };
+} | {
+  registration: {
+    chain_id: string;
+    name: string;
+    registration: Registration;
+  };
+} | {
+  tombstone: {
+    chain_id: string;
+    name: string;
+    tombstone: Tombstone;
+  };
+} | {
+  owner: {
+    chain_id: string;
+    name: string;
+    owner: Addr;
+  };
+} | {
+  tag: {
+    chain_id: string;
+    name: string;
+    tag: string;
+    version: string;
+  };
+} | {
+  migration: {
+    chain_id: string;
+    from_code_id: number;
+    migration: Migration;
+    to_code_id: number;
+  };
+} | {
+  deposit: {
+    chain_id: string;
+    code_id: number;
+    deposit: Deposit;
+    name: string;
+  };
+} | {
+  grace_period: {
+    chain_id: string;
+    code_id: number;
+    grace_period: GracePeriod;
+    name: string;
+  };
+} | {
+  fee_exemption: {
+    address: Addr;
+  };
+} | {
+  name_fee: {
+    name: string;
+    payment_info: PaymentInfo[];
+  };
+} | {
+  chain_id_fee: {
+    chain_id: string;
+    payment_info: PaymentInfo[];
+  };
+} | {
+  global_hook: {
+    hook: Addr;
+  };
+} | {
+  hook: {
+    chain_id: string;
+    hook: Addr;
+    name: string;
+  };
+} | {
+  ibc_connection: {
+    chain_id: string;
+    connection_id: string;
+  };
+} | {
+  config: {
+    config: Config;
+  };
+};
 export interface Cw20ReceiveMsg {
   amount: Uint128;
   msg: Binary;
   sender: string;
   [k: string]: unknown;
 }
+export interface SignedRegistration {
+  chain_id: string;
+  checksum: string;
+  code_id: number;
+  dependencies?: Dependency[] | null;
+  name: string;
+  version: string;
+}
+export interface InstantiatePackageMsg {
+  admin?: string | null;
+  funds: Coin[];
+  label: string;
+  msg: Binary;
+  name: string;
+  version?: string | null;
+}
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+  [k: string]: unknown;
+}
+export interface Tombstone {
+  code_id: number;
+  reason?: string | null;
+  removed_by: Addr;
+  version: string;
+}
+export interface Migration {
+  migrate_msg_schema_hash?: string | null;
+  notes?: string | null;
+}
+export interface Deposit {
+  amount: PaymentInfo;
+  depositor: Addr;
+  returned?: boolean;
+}
+export interface GracePeriod {
+  escrowed_fee?: PaymentInfo | null;
+  expires: Expiration;
+  payer: Addr;
+}
+export interface Config {
+  admin: Addr;
+  deposit?: PaymentInfo | null;
+  fee_recipients: [Addr, Decimal][];
+  hook_gas_limit?: number;
+  immutable?: boolean;
+  payment_info: PaymentInfo[];
+  rate_limit?: RateLimit | null;
+  refund_on_unregister?: boolean;
+  unregister_window?: Duration | null;
+  upstreams?: Addr[];
+  validate_chain_ids?: boolean;
+}
+export interface ExportResponse {
+  entries: ExportEntry[];
+}
+export interface GetChainResponse {
+  chain: Chain;
+}
+export interface GetFeeResponse {
+  payment_info: PaymentInfo[];
+}
+export interface GetNonceResponse {
+  nonce: number;
+}
 export interface GetRegistrationResponse {
+  name: string;
+  redirected_from?: string | null;
   registration: Registration;
+  source?: Addr | null;
 }
-export interface Registration {
-  checksum: string;
+export interface GetTaggedRegistrationResponse {
+  name: string;
+  redirected_from?: string | null;
+  registration: Registration;
+  source?: Addr | null;
+}
+export interface GetTombstoneResponse {
   code_id: number;
-  registered_by: Addr;
+  reason?: string | null;
+  removed_by: Addr;
   version: string;
 }
+export interface IdentifyContractResponse {
+  code_id: number;
+  contract_version?: ContractVersion | null;
+  name: string;
+  name_mismatch: boolean;
+  registration: Registration;
+  version_mismatch: boolean;
+}
+export interface ContractVersion {
+  contract: string;
+  version: string;
+  [k: string]: unknown;
+}
 export interface InfoForCodeIdResponse {
   checksum: string;
   name: string;
   registered_by: Addr;
+  source?: Addr | null;
   version: string;
 }
 export interface InstantiateMsg {
   admin: string;
-  payment_info: PaymentInfo;
+  fee_recipients?: [string, Decimal][] | null;
+  payment_info: PaymentInfo[];
+}
+export interface InstantiatePackageResponse {
+  contract_address: string;
+}
+export interface ListChainsResponse {
+  chains: Chain[];
+}
+export interface ListHooksResponse {
+  hooks: Addr[];
+}
+export interface ListIbcChannelsResponse {
+  channels: IbcChannelEntry[];
+}
+export interface IbcChannelEntry {
+  chain_id?: string | null;
+  channel_id: string;
+  connection_id: string;
+}
+export interface ListRegistrationsByRegistrantResponse {
+  registrations: RegistrantEntry[];
+}
+export interface RegistrantEntry {
+  chain_id: string;
+  name: string;
+  registration: Registration;
 }
 export interface ListRegistrationsResponse {
   registrations: Registration[];
 }
+export interface ListTagsResponse {
+  tags: [string, string][];
+}
+export interface MigrationPathResponse {
+  hops: MigrationHop[];
+}
+export interface MigrationHop {
+  from_code_id: number;
+  migration: Migration;
+  to_code_id: number;
+  to_version: string;
+}
+export interface PauseStatusResponse {
+  paused: boolean;
+  until?: Expiration | null;
+}
 export type QueryMsg = {
   config: {};
 } | {
   get_registration: {
     chain_id: string;
+    include_prerelease?: boolean | null;
     name: string;
     version?: string | null;
   };
+} | {
+  resolve_many: {
+    requests: ResolveRequest[];
+  };
 } | {
   info_for_code_id: {
     chain_id: string;
@@ -98,47 +539,373 @@ export type QueryMsg = {
 } | {
   list_registrations: {
     chain_id: string;
+    include_prerelease?: boolean | null;
     name: string;
   };
-};
-export type ReceiveMsg = {
-  register: {
+} | {
+  get_fee: {
     chain_id: string;
-    checksum: string;
-    code_id: number;
     name: string;
-    version: string;
+    sender: string;
   };
-};
-export interface CwCodeIdRegistryReadOnlyInterface {
-  contractAddress: string;
-  config: () => Promise<ConfigResponse>;
-  getRegistration: ({
-    chainId,
-    name,
-    version
-  }: {
-    chainId: string;
+} | {
+  pause_status: {};
+} | {
+  get_tagged_registration: {
+    chain_id: string;
     name: string;
-    version?: string;
-  }) => Promise<GetRegistrationResponse>;
-  infoForCodeId: ({
-    chainId,
-    codeId
-  }: {
-    chainId: string;
-    codeId: number;
-  }) => Promise<InfoForCodeIdResponse>;
-  listRegistrations: ({
-    chainId,
-    name
-  }: {
-    chainId: string;
+    tag: string;
+  };
+} | {
+  list_tags: {
+    chain_id: string;
     name: string;
-  }) => Promise<ListRegistrationsResponse>;
-}
-export class CwCodeIdRegistryQueryClient implements CwCodeIdRegistryReadOnlyInterface {
-  client: CosmWasmClient;
+  };
+} | {
+  list_registrations_by_registrant: {
+    address: string;
+    limit?: number | null;
+    start_after?: [string, number] | null;
+  };
+} | {
+  get_nonce: {
+    address: string;
+  };
+} | {
+  list_hooks: {
+    chain_id?: string | null;
+    name?: string | null;
+  };
+} | {
+  reverse_dependencies: {
+    chain_id: string;
+    limit?: number | null;
+    name: string;
+    start_after?: number | null;
+    version?: string | null;
+  };
+} | {
+  migration_path: {
+    chain_id: string;
+    from_code_id: number;
+    to_version?: string | null;
+  };
+} | {
+  identify_contract: {
+    address: string;
+  };
+} | {
+  get_chain: {
+    chain_id: string;
+  };
+} | {
+  get_tombstone: {
+    chain_id: string;
+    name: string;
+    version: string;
+  };
+} | {
+  list_chains: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  export: {
+    limit?: number | null;
+    start_after?: ExportKey | null;
+  };
+} | {
+  list_ibc_channels: {};
+};
+export type ExportKey = {
+  chain: {
+    chain_id: string;
+  };
+} | {
+  alias: {
+    alias: string;
+    chain_id: string;
+  };
+} | {
+  registration: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+  };
+} | {
+  tombstone: {
+    chain_id: string;
+    name: string;
+    version: string;
+  };
+} | {
+  owner: {
+    chain_id: string;
+    name: string;
+  };
+} | {
+  tag: {
+    chain_id: string;
+    name: string;
+    tag: string;
+  };
+} | {
+  migration: {
+    chain_id: string;
+    from_code_id: number;
+    to_code_id: number;
+  };
+} | {
+  deposit: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+  };
+} | {
+  grace_period: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+  };
+} | {
+  fee_exemption: {
+    address: Addr;
+  };
+} | {
+  name_fee: {
+    name: string;
+  };
+} | {
+  chain_id_fee: {
+    chain_id: string;
+  };
+} | {
+  global_hook: {
+    hook: Addr;
+  };
+} | {
+  hook: {
+    chain_id: string;
+    hook: Addr;
+    name: string;
+  };
+} | {
+  ibc_connection: {
+    connection_id: string;
+  };
+} | {
+  config: {};
+};
+export interface ResolveRequest {
+  chain_id: string;
+  include_prerelease?: boolean | null;
+  name: string;
+  version?: string | null;
+}
+export type ReceiveMsg = {
+  register: {
+    chain_id: string;
+    checksum: string;
+    code_id: number;
+    dependencies?: Dependency[] | null;
+    name: string;
+    version: string;
+  };
+} | {
+  register_signed: {
+    nonce: number;
+    pubkey: Binary;
+    registration: SignedRegistration;
+    signature: Binary;
+  };
+};
+export interface RegisterSignedPayload {
+  nonce: number;
+  registration: SignedRegistration;
+  registry: string;
+  registry_chain_id: string;
+}
+export type RegistryAck = {
+  result: Binary;
+} | {
+  error: string;
+};
+export type RegistryPacket = {
+  register: {
+    chain_id: string;
+    name: string;
+    registration: Registration;
+  };
+} | {
+  set_owner: {
+    chain_id: string;
+    name: string;
+    owner?: Addr | null;
+  };
+} | {
+  yank: {
+    chain_id: string;
+    code_id: number;
+    name: string;
+    yanked: boolean;
+  };
+};
+export interface ResolveManyResponse {
+  results: ResolveResult[];
+}
+export interface ResolveResult {
+  error?: string | null;
+  registration?: GetRegistrationResponse | null;
+}
+export interface ReverseDependenciesResponse {
+  dependents: ReverseDependency[];
+}
+export interface ReverseDependency {
+  name: string;
+  registration: Registration;
+  version_req: string;
+}
+export interface CwCodeIdRegistryReadOnlyInterface {
+  contractAddress: string;
+  config: () => Promise<ConfigResponse>;
+  getRegistration: ({
+    chainId,
+    includePrerelease,
+    name,
+    version
+  }: {
+    chainId: string;
+    includePrerelease?: boolean;
+    name: string;
+    version?: string;
+  }) => Promise<GetRegistrationResponse>;
+  resolveMany: ({
+    requests
+  }: {
+    requests: ResolveRequest[];
+  }) => Promise<ResolveManyResponse>;
+  infoForCodeId: ({
+    chainId,
+    codeId
+  }: {
+    chainId: string;
+    codeId: number;
+  }) => Promise<InfoForCodeIdResponse>;
+  listRegistrations: ({
+    chainId,
+    includePrerelease,
+    name
+  }: {
+    chainId: string;
+    includePrerelease?: boolean;
+    name: string;
+  }) => Promise<ListRegistrationsResponse>;
+  getFee: ({
+    chainId,
+    name,
+    sender
+  }: {
+    chainId: string;
+    name: string;
+    sender: string;
+  }) => Promise<GetFeeResponse>;
+  pauseStatus: () => Promise<PauseStatusResponse>;
+  getTaggedRegistration: ({
+    chainId,
+    name,
+    tag
+  }: {
+    chainId: string;
+    name: string;
+    tag: string;
+  }) => Promise<GetTaggedRegistrationResponse>;
+  listTags: ({
+    chainId,
+    name
+  }: {
+    chainId: string;
+    name: string;
+  }) => Promise<ListTagsResponse>;
+  listRegistrationsByRegistrant: ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: [string, number];
+  }) => Promise<ListRegistrationsByRegistrantResponse>;
+  getNonce: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<GetNonceResponse>;
+  listHooks: ({
+    chainId,
+    name
+  }: {
+    chainId?: string;
+    name?: string;
+  }) => Promise<ListHooksResponse>;
+  reverseDependencies: ({
+    chainId,
+    limit,
+    name,
+    startAfter,
+    version
+  }: {
+    chainId: string;
+    limit?: number;
+    name: string;
+    startAfter?: number;
+    version?: string;
+  }) => Promise<ReverseDependenciesResponse>;
+  migrationPath: ({
+    chainId,
+    fromCodeId,
+    toVersion
+  }: {
+    chainId: string;
+    fromCodeId: number;
+    toVersion?: string;
+  }) => Promise<MigrationPathResponse>;
+  identifyContract: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<IdentifyContractResponse>;
+  getChain: ({
+    chainId
+  }: {
+    chainId: string;
+  }) => Promise<GetChainResponse>;
+  getTombstone: ({
+    chainId,
+    name,
+    version
+  }: {
+    chainId: string;
+    name: string;
+    version: string;
+  }) => Promise<GetTombstoneResponse>;
+  listChains: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ListChainsResponse>;
+  export: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: ExportKey;
+  }) => Promise<ExportResponse>;
+  listIbcChannels: () => Promise<ListIbcChannelsResponse>;
+}
+export class CwCodeIdRegistryQueryClient implements CwCodeIdRegistryReadOnlyInterface {
+  client: CosmWasmClient;
   contractAddress: string;
 
   constructor(client: CosmWasmClient, contractAddress: string) {
@@ -146,8 +913,24 @@ export class CwCodeIdRegistryQueryClient implements CwCodeIdRegistryReadOnlyInte
     this.contractAddress = contractAddress;
     this.config = this.config.bind(this);
     this.getRegistration = this.getRegistration.bind(this);
+    this.resolveMany = this.resolveMany.bind(this);
     this.infoForCodeId = this.infoForCodeId.bind(this);
     this.listRegistrations = this.listRegistrations.bind(this);
+    this.getFee = this.getFee.bind(this);
+    this.pauseStatus = this.pauseStatus.bind(this);
+    this.getTaggedRegistration = this.getTaggedRegistration.bind(this);
+    this.listTags = this.listTags.bind(this);
+    this.listRegistrationsByRegistrant = this.listRegistrationsByRegistrant.bind(this);
+    this.getNonce = this.getNonce.bind(this);
+    this.listHooks = this.listHooks.bind(this);
+    this.reverseDependencies = this.reverseDependencies.bind(this);
+    this.migrationPath = this.migrationPath.bind(this);
+    this.identifyContract = this.identifyContract.bind(this);
+    this.getChain = this.getChain.bind(this);
+    this.getTombstone = this.getTombstone.bind(this);
+    this.listChains = this.listChains.bind(this);
+    this.export = this.export.bind(this);
+    this.listIbcChannels = this.listIbcChannels.bind(this);
   }
 
   config = async (): Promise<ConfigResponse> => {
@@ -155,178 +938,1119 @@ export class CwCodeIdRegistryQueryClient implements CwCodeIdRegistryReadOnlyInte
       config: {}
     });
   };
-  getRegistration = async ({
+  getRegistration = async ({
+    chainId,
+    includePrerelease,
+    name,
+    version
+  }: {
+    chainId: string;
+    includePrerelease?: boolean;
+    name: string;
+    version?: string;
+  }): Promise<GetRegistrationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_registration: {
+        chain_id: chainId,
+        include_prerelease: includePrerelease,
+        name,
+        version
+      }
+    });
+  };
+  resolveMany = async ({
+    requests
+  }: {
+    requests: ResolveRequest[];
+  }): Promise<ResolveManyResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      resolve_many: {
+        requests
+      }
+    });
+  };
+  infoForCodeId = async ({
+    chainId,
+    codeId
+  }: {
+    chainId: string;
+    codeId: number;
+  }): Promise<InfoForCodeIdResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      info_for_code_id: {
+        chain_id: chainId,
+        code_id: codeId
+      }
+    });
+  };
+  listRegistrations = async ({
+    chainId,
+    includePrerelease,
+    name
+  }: {
+    chainId: string;
+    includePrerelease?: boolean;
+    name: string;
+  }): Promise<ListRegistrationsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_registrations: {
+        chain_id: chainId,
+        include_prerelease: includePrerelease,
+        name
+      }
+    });
+  };
+  getFee = async ({
+    chainId,
+    name,
+    sender
+  }: {
+    chainId: string;
+    name: string;
+    sender: string;
+  }): Promise<GetFeeResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_fee: {
+        chain_id: chainId,
+        name,
+        sender
+      }
+    });
+  };
+  pauseStatus = async (): Promise<PauseStatusResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      pause_status: {}
+    });
+  };
+  getTaggedRegistration = async ({
+    chainId,
+    name,
+    tag
+  }: {
+    chainId: string;
+    name: string;
+    tag: string;
+  }): Promise<GetTaggedRegistrationResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_tagged_registration: {
+        chain_id: chainId,
+        name,
+        tag
+      }
+    });
+  };
+  listTags = async ({
+    chainId,
+    name
+  }: {
+    chainId: string;
+    name: string;
+  }): Promise<ListTagsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_tags: {
+        chain_id: chainId,
+        name
+      }
+    });
+  };
+  listRegistrationsByRegistrant = async ({
+    address,
+    limit,
+    startAfter
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: [string, number];
+  }): Promise<ListRegistrationsByRegistrantResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_registrations_by_registrant: {
+        address,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  getNonce = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<GetNonceResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_nonce: {
+        address
+      }
+    });
+  };
+  listHooks = async ({
+    chainId,
+    name
+  }: {
+    chainId?: string;
+    name?: string;
+  }): Promise<ListHooksResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_hooks: {
+        chain_id: chainId,
+        name
+      }
+    });
+  };
+  reverseDependencies = async ({
+    chainId,
+    limit,
+    name,
+    startAfter,
+    version
+  }: {
+    chainId: string;
+    limit?: number;
+    name: string;
+    startAfter?: number;
+    version?: string;
+  }): Promise<ReverseDependenciesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reverse_dependencies: {
+        chain_id: chainId,
+        limit,
+        name,
+        start_after: startAfter,
+        version
+      }
+    });
+  };
+  migrationPath = async ({
+    chainId,
+    fromCodeId,
+    toVersion
+  }: {
+    chainId: string;
+    fromCodeId: number;
+    toVersion?: string;
+  }): Promise<MigrationPathResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      migration_path: {
+        chain_id: chainId,
+        from_code_id: fromCodeId,
+        to_version: toVersion
+      }
+    });
+  };
+  identifyContract = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<IdentifyContractResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      identify_contract: {
+        address
+      }
+    });
+  };
+  getChain = async ({
+    chainId
+  }: {
+    chainId: string;
+  }): Promise<GetChainResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_chain: {
+        chain_id: chainId
+      }
+    });
+  };
+  getTombstone = async ({
+    chainId,
+    name,
+    version
+  }: {
+    chainId: string;
+    name: string;
+    version: string;
+  }): Promise<GetTombstoneResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      get_tombstone: {
+        chain_id: chainId,
+        name,
+        version
+      }
+    });
+  };
+  listChains = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ListChainsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_chains: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  export = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: ExportKey;
+  }): Promise<ExportResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      export: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  listIbcChannels = async (): Promise<ListIbcChannelsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      list_ibc_channels: {}
+    });
+  };
+}
+export interface CwCodeIdRegistryInterface extends CwCodeIdRegistryReadOnlyInterface {
+  contractAddress: string;
+  sender: string;
+  receive: ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: string;
+    msg: string;
+    sender: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  register: ({
+    chainId,
+    checksum,
+    codeId,
+    dependencies,
+    name,
+    version
+  }: {
+    chainId: string;
+    checksum: string;
+    codeId: number;
+    dependencies?: Dependency[];
+    name: string;
+    version: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  registerSigned: ({
+    nonce,
+    pubkey,
+    registration,
+    signature
+  }: {
+    nonce: number;
+    pubkey: Binary;
+    registration: SignedRegistration;
+    signature: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  instantiatePackage: ({
+    admin,
+    funds,
+    label,
+    msg,
+    name,
+    version
+  }: {
+    admin?: string;
+    funds: Coin[];
+    label: string;
+    msg: string;
+    name: string;
+    version?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, _funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setOwner: ({
+    chainId,
+    name,
+    owner
+  }: {
+    chainId: string;
+    name: string;
+    owner?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  unregister: ({
+    chainId,
+    codeId,
+    name,
+    reason
+  }: {
+    chainId: string;
+    codeId: number;
+    name: string;
+    reason?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  updateConfig: ({
+    admin,
+    feeRecipients,
+    paymentInfo,
+    validateChainIds
+  }: {
+    admin?: string;
+    feeRecipients?: [string, Decimal][];
+    paymentInfo?: PaymentInfo[];
+    validateChainIds?: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  updateFeeExemptions: ({
+    toAdd,
+    toRemove
+  }: {
+    toAdd: string[];
+    toRemove: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setNameFee: ({
+    name,
+    paymentInfo
+  }: {
+    name: string;
+    paymentInfo?: PaymentInfo[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setChainIdFee: ({
+    chainId,
+    paymentInfo
+  }: {
+    chainId: string;
+    paymentInfo?: PaymentInfo[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  pause: ({
+    until
+  }: {
+    until?: Expiration;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  unpause: (fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setTag: ({
+    chainId,
+    name,
+    tag,
+    version
+  }: {
+    chainId: string;
+    name: string;
+    tag: string;
+    version: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeTag: ({
+    chainId,
+    name,
+    tag
+  }: {
+    chainId: string;
+    name: string;
+    tag: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setAlias: ({
+    alias,
+    chainId,
+    name
+  }: {
+    alias: string;
+    chainId: string;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeAlias: ({
+    alias,
+    chainId
+  }: {
+    alias: string;
+    chainId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  rename: ({
+    chainId,
+    name,
+    newName
+  }: {
+    chainId: string;
+    name: string;
+    newName: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  declareMigration: ({
+    chainId,
+    fromVersion,
+    migrateMsgSchemaHash,
+    name,
+    notes,
+    toVersion
+  }: {
+    chainId: string;
+    fromVersion: string;
+    migrateMsgSchemaHash?: string;
+    name: string;
+    notes?: string;
+    toVersion: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeMigration: ({
+    chainId,
+    fromVersion,
+    name,
+    toVersion
+  }: {
+    chainId: string;
+    fromVersion: string;
+    name: string;
+    toVersion: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  addHook: ({
+    addr,
+    chainId,
+    name
+  }: {
+    addr: string;
+    chainId?: string;
+    name?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeHook: ({
+    addr,
+    chainId,
+    name
+  }: {
+    addr: string;
+    chainId?: string;
+    name?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setHookGasLimit: ({
+    gasLimit
+  }: {
+    gasLimit: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setChain: ({
+    chain
+  }: {
+    chain: Chain;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeChain: ({
+    chainId
+  }: {
+    chainId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  import: ({
+    entries
+  }: {
+    entries: ExportEntry[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  sealImport: (fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setUpstreams: ({
+    upstreams
+  }: {
+    upstreams: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setIbcConnection: ({
+    chainId,
+    connectionId
+  }: {
+    chainId: string;
+    connectionId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  removeIbcConnection: ({
+    connectionId
+  }: {
+    connectionId: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setUnregisterWindow: ({
+    refund,
+    window
+  }: {
+    refund: boolean;
+    window?: Duration;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setImmutable: ({
+    immutable
+  }: {
+    immutable: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  setRegistrationLimits: ({
+    deposit,
+    rateLimit
+  }: {
+    deposit?: PaymentInfo;
+    rateLimit?: RateLimit;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  releaseFee: ({
+    chainId,
+    codeId,
+    name
+  }: {
+    chainId: string;
+    codeId: number;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  yank: ({
+    chainId,
+    codeId,
+    name
+  }: {
+    chainId: string;
+    codeId: number;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+  unyank: ({
+    chainId,
+    codeId,
+    name
+  }: {
+    chainId: string;
+    codeId: number;
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
+}
+export class CwCodeIdRegistryClient extends CwCodeIdRegistryQueryClient implements CwCodeIdRegistryInterface {
+  client: SigningCosmWasmClient;
+  sender: string;
+  contractAddress: string;
+
+  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
+    super(client, contractAddress);
+    this.client = client;
+    this.sender = sender;
+    this.contractAddress = contractAddress;
+    this.receive = this.receive.bind(this);
+    this.register = this.register.bind(this);
+    this.registerSigned = this.registerSigned.bind(this);
+    this.instantiatePackage = this.instantiatePackage.bind(this);
+    this.setOwner = this.setOwner.bind(this);
+    this.unregister = this.unregister.bind(this);
+    this.updateConfig = this.updateConfig.bind(this);
+    this.updateFeeExemptions = this.updateFeeExemptions.bind(this);
+    this.setNameFee = this.setNameFee.bind(this);
+    this.setChainIdFee = this.setChainIdFee.bind(this);
+    this.pause = this.pause.bind(this);
+    this.unpause = this.unpause.bind(this);
+    this.setTag = this.setTag.bind(this);
+    this.removeTag = this.removeTag.bind(this);
+    this.setAlias = this.setAlias.bind(this);
+    this.removeAlias = this.removeAlias.bind(this);
+    this.rename = this.rename.bind(this);
+    this.declareMigration = this.declareMigration.bind(this);
+    this.removeMigration = this.removeMigration.bind(this);
+    this.addHook = this.addHook.bind(this);
+    this.removeHook = this.removeHook.bind(this);
+    this.setHookGasLimit = this.setHookGasLimit.bind(this);
+    this.setChain = this.setChain.bind(this);
+    this.removeChain = this.removeChain.bind(this);
+    this.import = this.import.bind(this);
+    this.sealImport = this.sealImport.bind(this);
+    this.setUpstreams = this.setUpstreams.bind(this);
+    this.setIbcConnection = this.setIbcConnection.bind(this);
+    this.removeIbcConnection = this.removeIbcConnection.bind(this);
+    this.setUnregisterWindow = this.setUnregisterWindow.bind(this);
+    this.setImmutable = this.setImmutable.bind(this);
+    this.setRegistrationLimits = this.setRegistrationLimits.bind(this);
+    this.releaseFee = this.releaseFee.bind(this);
+    this.yank = this.yank.bind(this);
+    this.unyank = this.unyank.bind(this);
+  }
+
+  receive = async ({
+    amount,
+    msg,
+    sender
+  }: {
+    amount: string;
+    msg: string;
+    sender: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      receive: {
+        amount,
+        msg,
+        sender
+      }
+    }, fee, memo, funds);
+  };
+  register = async ({
+    chainId,
+    checksum,
+    codeId,
+    dependencies,
+    name,
+    version
+  }: {
+    chainId: string;
+    checksum: string;
+    codeId: number;
+    dependencies?: Dependency[];
+    name: string;
+    version: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      register: {
+        chain_id: chainId,
+        checksum,
+        code_id: codeId,
+        dependencies,
+        name,
+        version
+      }
+    }, fee, memo, funds);
+  };
+  registerSigned = async ({
+    nonce,
+    pubkey,
+    registration,
+    signature
+  }: {
+    nonce: number;
+    pubkey: Binary;
+    registration: SignedRegistration;
+    signature: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      register_signed: {
+        nonce,
+        pubkey,
+        registration,
+        signature
+      }
+    }, fee, memo, funds);
+  };
+  instantiatePackage = async ({
+    admin,
+    funds,
+    label,
+    msg,
+    name,
+    version
+  }: {
+    admin?: string;
+    funds: Coin[];
+    label: string;
+    msg: string;
+    name: string;
+    version?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, _funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      instantiate_package: {
+        admin,
+        funds,
+        label,
+        msg,
+        name,
+        version
+      }
+    }, fee, memo, _funds);
+  };
+  setOwner = async ({
+    chainId,
+    name,
+    owner
+  }: {
+    chainId: string;
+    name: string;
+    owner?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_owner: {
+        chain_id: chainId,
+        name,
+        owner
+      }
+    }, fee, memo, funds);
+  };
+  unregister = async ({
+    chainId,
+    codeId,
+    name,
+    reason
+  }: {
+    chainId: string;
+    codeId: number;
+    name: string;
+    reason?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unregister: {
+        chain_id: chainId,
+        code_id: codeId,
+        name,
+        reason
+      }
+    }, fee, memo, funds);
+  };
+  updateConfig = async ({
+    admin,
+    feeRecipients,
+    paymentInfo,
+    validateChainIds
+  }: {
+    admin?: string;
+    feeRecipients?: [string, Decimal][];
+    paymentInfo?: PaymentInfo[];
+    validateChainIds?: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_config: {
+        admin,
+        fee_recipients: feeRecipients,
+        payment_info: paymentInfo,
+        validate_chain_ids: validateChainIds
+      }
+    }, fee, memo, funds);
+  };
+  updateFeeExemptions = async ({
+    toAdd,
+    toRemove
+  }: {
+    toAdd: string[];
+    toRemove: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_fee_exemptions: {
+        to_add: toAdd,
+        to_remove: toRemove
+      }
+    }, fee, memo, funds);
+  };
+  setNameFee = async ({
+    name,
+    paymentInfo
+  }: {
+    name: string;
+    paymentInfo?: PaymentInfo[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_name_fee: {
+        name,
+        payment_info: paymentInfo
+      }
+    }, fee, memo, funds);
+  };
+  setChainIdFee = async ({
+    chainId,
+    paymentInfo
+  }: {
+    chainId: string;
+    paymentInfo?: PaymentInfo[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_chain_id_fee: {
+        chain_id: chainId,
+        payment_info: paymentInfo
+      }
+    }, fee, memo, funds);
+  };
+  pause = async ({
+    until
+  }: {
+    until?: Expiration;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      pause: {
+        until
+      }
+    }, fee, memo, funds);
+  };
+  unpause = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unpause: {}
+    }, fee, memo, funds);
+  };
+  setTag = async ({
     chainId,
     name,
+    tag,
     version
   }: {
     chainId: string;
     name: string;
-    version?: string;
-  }): Promise<GetRegistrationResponse> => {
-    return this.client.queryContractSmart(this.contractAddress, {
-      get_registration: {
+    tag: string;
+    version: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_tag: {
         chain_id: chainId,
         name,
+        tag,
         version
       }
-    });
+    }, fee, memo, funds);
   };
-  infoForCodeId = async ({
+  removeTag = async ({
     chainId,
-    codeId
+    name,
+    tag
   }: {
     chainId: string;
-    codeId: number;
-  }): Promise<InfoForCodeIdResponse> => {
-    return this.client.queryContractSmart(this.contractAddress, {
-      info_for_code_id: {
+    name: string;
+    tag: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_tag: {
         chain_id: chainId,
-        code_id: codeId
+        name,
+        tag
       }
-    });
+    }, fee, memo, funds);
   };
-  listRegistrations = async ({
+  setAlias = async ({
+    alias,
     chainId,
     name
   }: {
+    alias: string;
     chainId: string;
     name: string;
-  }): Promise<ListRegistrationsResponse> => {
-    return this.client.queryContractSmart(this.contractAddress, {
-      list_registrations: {
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_alias: {
+        alias,
         chain_id: chainId,
         name
       }
-    });
+    }, fee, memo, funds);
   };
-}
-export interface CwCodeIdRegistryInterface extends CwCodeIdRegistryReadOnlyInterface {
-  contractAddress: string;
-  sender: string;
-  receive: ({
-    amount,
-    msg,
-    sender
+  removeAlias = async ({
+    alias,
+    chainId
   }: {
-    amount: string;
-    msg: string;
-    sender: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
-  register: ({
+    alias: string;
+    chainId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_alias: {
+        alias,
+        chain_id: chainId
+      }
+    }, fee, memo, funds);
+  };
+  rename = async ({
     chainId,
-    checksum,
-    codeId,
     name,
-    version
+    newName
   }: {
     chainId: string;
-    checksum: string;
-    codeId: number;
     name: string;
-    version: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
-  setOwner: ({
+    newName: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rename: {
+        chain_id: chainId,
+        name,
+        new_name: newName
+      }
+    }, fee, memo, funds);
+  };
+  declareMigration = async ({
     chainId,
+    fromVersion,
+    migrateMsgSchemaHash,
     name,
-    owner
+    notes,
+    toVersion
   }: {
     chainId: string;
+    fromVersion: string;
+    migrateMsgSchemaHash?: string;
     name: string;
-    owner?: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
-  unregister: ({
+    notes?: string;
+    toVersion: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      declare_migration: {
+        chain_id: chainId,
+        from_version: fromVersion,
+        migrate_msg_schema_hash: migrateMsgSchemaHash,
+        name,
+        notes,
+        to_version: toVersion
+      }
+    }, fee, memo, funds);
+  };
+  removeMigration = async ({
     chainId,
-    codeId,
-    name
+    fromVersion,
+    name,
+    toVersion
   }: {
     chainId: string;
-    codeId: number;
+    fromVersion: string;
     name: string;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
-  updateConfig: ({
-    admin,
-    paymentInfo
+    toVersion: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_migration: {
+        chain_id: chainId,
+        from_version: fromVersion,
+        name,
+        to_version: toVersion
+      }
+    }, fee, memo, funds);
+  };
+  addHook = async ({
+    addr,
+    chainId,
+    name
   }: {
-    admin?: string;
-    paymentInfo?: PaymentInfo;
-  }, fee?: number | StdFee | "auto", memo?: string, funds?: readonly Coin[]) => Promise<ExecuteResult>;
-}
-export class CwCodeIdRegistryClient extends CwCodeIdRegistryQueryClient implements CwCodeIdRegistryInterface {
-  client: SigningCosmWasmClient;
-  sender: string;
-  contractAddress: string;
-
-  constructor(client: SigningCosmWasmClient, sender: string, contractAddress: string) {
-    super(client, contractAddress);
-    this.client = client;
-    this.sender = sender;
-    this.contractAddress = contractAddress;
-    this.receive = this.receive.bind(this);
-    this.register = this.register.bind(this);
-    this.setOwner = this.setOwner.bind(this);
-    this.unregister = this.unregister.bind(this);
-    this.updateConfig = this.updateConfig.bind(this);
-  }
-
-  receive = async ({
-    amount,
-    msg,
-    sender
+    addr: string;
+    chainId?: string;
+    name?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_hook: {
+        addr,
+        chain_id: chainId,
+        name
+      }
+    }, fee, memo, funds);
+  };
+  removeHook = async ({
+    addr,
+    chainId,
+    name
   }: {
-    amount: string;
-    msg: string;
-    sender: string;
+    addr: string;
+    chainId?: string;
+    name?: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      receive: {
-        amount,
-        msg,
-        sender
+      remove_hook: {
+        addr,
+        chain_id: chainId,
+        name
       }
     }, fee, memo, funds);
   };
-  register = async ({
+  setHookGasLimit = async ({
+    gasLimit
+  }: {
+    gasLimit: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_hook_gas_limit: {
+        gas_limit: gasLimit
+      }
+    }, fee, memo, funds);
+  };
+  setChain = async ({
+    chain
+  }: {
+    chain: Chain;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_chain: {
+        chain
+      }
+    }, fee, memo, funds);
+  };
+  removeChain = async ({
+    chainId
+  }: {
+    chainId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_chain: {
+        chain_id: chainId
+      }
+    }, fee, memo, funds);
+  };
+  import = async ({
+    entries
+  }: {
+    entries: ExportEntry[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      import: {
+        entries
+      }
+    }, fee, memo, funds);
+  };
+  sealImport = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      seal_import: {}
+    }, fee, memo, funds);
+  };
+  setUpstreams = async ({
+    upstreams
+  }: {
+    upstreams: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_upstreams: {
+        upstreams
+      }
+    }, fee, memo, funds);
+  };
+  setIbcConnection = async ({
     chainId,
-    checksum,
-    codeId,
-    name,
-    version
+    connectionId
   }: {
     chainId: string;
-    checksum: string;
-    codeId: number;
-    name: string;
-    version: string;
+    connectionId: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      register: {
+      set_ibc_connection: {
         chain_id: chainId,
-        checksum,
-        code_id: codeId,
-        name,
-        version
+        connection_id: connectionId
       }
     }, fee, memo, funds);
   };
-  setOwner = async ({
+  removeIbcConnection = async ({
+    connectionId
+  }: {
+    connectionId: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_ibc_connection: {
+        connection_id: connectionId
+      }
+    }, fee, memo, funds);
+  };
+  setUnregisterWindow = async ({
+    refund,
+    window
+  }: {
+    refund: boolean;
+    window?: Duration;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_unregister_window: {
+        refund,
+        window
+      }
+    }, fee, memo, funds);
+  };
+  setImmutable = async ({
+    immutable
+  }: {
+    immutable: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_immutable: {
+        immutable
+      }
+    }, fee, memo, funds);
+  };
+  setRegistrationLimits = async ({
+    deposit,
+    rateLimit
+  }: {
+    deposit?: PaymentInfo;
+    rateLimit?: RateLimit;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_registration_limits: {
+        deposit,
+        rate_limit: rateLimit
+      }
+    }, fee, memo, funds);
+  };
+  releaseFee = async ({
     chainId,
-    name,
-    owner
+    codeId,
+    name
   }: {
     chainId: string;
+    codeId: number;
     name: string;
-    owner?: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      set_owner: {
+      release_fee: {
         chain_id: chainId,
-        name,
-        owner
+        code_id: codeId,
+        name
       }
     }, fee, memo, funds);
   };
-  unregister = async ({
+  yank = async ({
     chainId,
     codeId,
     name
@@ -336,24 +2060,27 @@ export class CwCodeIdRegistryClient extends CwCodeIdRegistryQueryClient implemen
     name: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      unregister: {
+      yank: {
         chain_id: chainId,
         code_id: codeId,
         name
       }
     }, fee, memo, funds);
   };
-  updateConfig = async ({
-    admin,
-    paymentInfo
+  unyank = async ({
+    chainId,
+    codeId,
+    name
   }: {
-    admin?: string;
-    paymentInfo?: PaymentInfo;
+    chainId: string;
+    codeId: number;
+    name: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: readonly Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      update_config: {
-        admin,
-        payment_info: paymentInfo
+      unyank: {
+        chain_id: chainId,
+        code_id: codeId,
+        name
       }
     }, fee, memo, funds);
   };